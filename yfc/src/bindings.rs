@@ -0,0 +1,292 @@
+use web_sys::{
+    wasm_bindgen::JsCast, EventTarget, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement,
+};
+use yew::prelude::*;
+
+use crate::{
+    components::form_field::{touch_callback, use_aria},
+    field::Field,
+    form::Form,
+    form_state::{StateMut, StateProvider, ValueStateMut},
+    form_value::FormValue,
+};
+
+/// Everything needed to bind a text-like control to a field, returned by [`use_input_binding`].
+///
+/// The components are built on these, so custom markup spreading them behaves the same way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputBinding {
+    pub id: Option<AttrValue>,
+    pub name: Option<AttrValue>,
+    pub placeholder: Option<AttrValue>,
+    pub value: AttrValue,
+    pub dirty: bool,
+    pub valid: bool,
+    pub touched: bool,
+    pub aria_invalid: Option<AttrValue>,
+    pub aria_required: Option<AttrValue>,
+    pub aria_describedby: Option<AttrValue>,
+    /// Sets the value of the field, for controls that don't emit input events
+    pub onset: Callback<AttrValue>,
+    /// Reads the value of an `input`, `textarea` or `select`
+    pub oninput: Callback<InputEvent>,
+    /// Reads the value of an `input`, `textarea` or `select`
+    pub onchange: Callback<Event>,
+    /// Marks the field as touched
+    pub onblur: Callback<FocusEvent>,
+}
+
+impl InputBinding {
+    /** Returns `valid` or `invalid` once the field is dirty, the way components apply `classes_valid` and `classes_invalid`. */
+    pub fn state_classes(&self, valid: &Classes, invalid: &Classes) -> Option<Classes> {
+        self.dirty.then(|| match self.valid {
+            true => valid.clone(),
+            false => invalid.clone(),
+        })
+    }
+}
+
+/// Everything needed to bind a checkbox to a `bool` field, returned by [`use_checkbox_binding`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckboxBinding {
+    pub id: Option<AttrValue>,
+    pub name: Option<AttrValue>,
+    pub checked: bool,
+    pub dirty: bool,
+    pub valid: bool,
+    pub touched: bool,
+    pub aria_invalid: Option<AttrValue>,
+    pub aria_required: Option<AttrValue>,
+    pub aria_describedby: Option<AttrValue>,
+    /// Sets whether the field is checked
+    pub onset: Callback<bool>,
+    /// Toggles the field
+    pub onchange: Callback<Event>,
+    /// Marks the field as touched
+    pub onblur: Callback<FocusEvent>,
+}
+
+impl CheckboxBinding {
+    /** See [`InputBinding::state_classes`]. */
+    pub fn state_classes(&self, valid: &Classes, invalid: &Classes) -> Option<Classes> {
+        self.dirty.then(|| match self.valid {
+            true => valid.clone(),
+            false => invalid.clone(),
+        })
+    }
+}
+
+/** Returns the value of the `input`, `textarea` or `select` an event targets. */
+fn target_value(target: Option<EventTarget>) -> Option<String> {
+    let target = target?;
+
+    if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+        Some(input.value())
+    } else if let Some(textarea) = target.dyn_ref::<HtmlTextAreaElement>() {
+        Some(textarea.value())
+    } else {
+        target
+            .dyn_ref::<HtmlSelectElement>()
+            .map(HtmlSelectElement::value)
+    }
+}
+
+/** Emits `first` and then `then`, used to run the callbacks of a binding before the ones of a component. */
+pub(crate) fn chain<E: Clone + 'static>(first: &Callback<E>, then: &Callback<E>) -> Callback<E> {
+    let (first, then) = (first.clone(), then.clone());
+
+    Callback::from(move |e: E| {
+        first.emit(e.clone());
+        then.emit(e);
+    })
+}
+
+/// Applies the result of a check done by a component itself, e.g. the length of a
+/// [`TextArea`](crate::TextArea), to its field.
+///
+/// An error makes the field invalid unless something else already did, `failed` remembers
+/// whether the check did so it only makes the field valid again when it was the one failing.
+pub(crate) fn apply_check<T>(form: &Form<T>, failed: &mut bool, error: Option<String>)
+where
+    T: StateProvider<State = Field>,
+{
+    let (valid, message) = match error {
+        Some(error) if *failed || form.state().valid() => (false, error),
+        None if *failed => (true, String::new()),
+        _ => return,
+    };
+    *failed = !valid;
+
+    // Left alone when nothing changes, as writing re-renders the form
+    {
+        let field = form.state();
+        #[cfg(feature = "validator")]
+        let unchanged = field.valid() == valid && field.message() == message;
+        #[cfg(not(feature = "validator"))]
+        let unchanged = field.valid() == valid;
+
+        if unchanged {
+            return;
+        }
+    }
+
+    let (_, mut field) = form.state_mut().split();
+    field.set_valid(valid);
+    #[cfg(feature = "validator")]
+    field.set_message(message);
+    #[cfg(not(feature = "validator"))]
+    let _ = message;
+}
+
+/** Binds a text-like control, e.g. an `input`, `textarea` or `select`, to a field. */
+#[hook]
+pub fn use_input_binding<T>(form: &Form<T>) -> InputBinding
+where
+    T: FormValue + 'static,
+{
+    let meta = form.meta();
+    let aria = use_aria(form);
+    let (value, dirty, valid, touched) = {
+        let field = form.state();
+        (
+            AttrValue::from(field.value().to_owned()),
+            field.dirty(),
+            field.valid(),
+            field.touched(),
+        )
+    };
+
+    let onset = {
+        let form = form.clone();
+        Callback::from(move |value: AttrValue| form.set(&value))
+    };
+    let oninput = {
+        let onset = onset.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(value) = target_value(e.target()) {
+                onset.emit(value.into());
+            }
+        })
+    };
+    let onchange = {
+        let onset = onset.clone();
+        Callback::from(move |e: Event| {
+            if let Some(value) = target_value(e.target()) {
+                onset.emit(value.into());
+            }
+        })
+    };
+
+    InputBinding {
+        id: meta.id(),
+        name: meta.name(),
+        placeholder: meta.placeholder().cloned(),
+        value,
+        dirty,
+        valid,
+        touched,
+        aria_invalid: aria.invalid,
+        aria_required: aria.required,
+        aria_describedby: aria.described_by,
+        onset,
+        oninput,
+        onchange,
+        onblur: transform_callback(form),
+    }
+}
+
+/** Marks the field as touched, applying its transform first when it runs on blur. */
+fn transform_callback<T>(form: &Form<T>) -> Callback<FocusEvent>
+where
+    T: FormValue + 'static,
+{
+    let form = form.clone();
+    let touch = touch_callback(&form);
+
+    Callback::from(move |e: FocusEvent| {
+        if form.meta().transform().is_some_and(|t| t.is_on_blur()) {
+            form.apply_transform();
+        }
+        touch.emit(e);
+    })
+}
+
+/** Binds a checkbox to a `bool` field. */
+#[hook]
+pub fn use_checkbox_binding(form: &Form<bool>) -> CheckboxBinding {
+    let checked = *form.value();
+
+    use_checkbox_binding_for(
+        form,
+        CheckboxValues {
+            checked,
+            checked_value: "true".into(),
+            unchecked_value: "false".into(),
+            toggled_value: (!checked).to_string().into(),
+        },
+    )
+}
+
+/// The values a checkbox bound with [`use_checkbox_binding_for`] reads and writes.
+pub(crate) struct CheckboxValues {
+    /// Whether the value of the field checks the checkbox
+    pub checked: bool,
+    /// Written when the checkbox is set to checked
+    pub checked_value: AttrValue,
+    /// Written when the checkbox is set to unchecked
+    pub unchecked_value: AttrValue,
+    /// Written when the checkbox is toggled, which isn't always the opposite of `checked`, e.g.
+    /// for a checkbox with an indeterminate state
+    pub toggled_value: AttrValue,
+}
+
+/** Binds a checkbox to any field, writing the given values into it. */
+#[hook]
+pub(crate) fn use_checkbox_binding_for<T>(form: &Form<T>, values: CheckboxValues) -> CheckboxBinding
+where
+    T: FormValue + 'static,
+{
+    let meta = form.meta();
+    let aria = use_aria(form);
+    let (dirty, valid, touched) = {
+        let field = form.state();
+        (field.dirty(), field.valid(), field.touched())
+    };
+    let CheckboxValues {
+        checked,
+        checked_value,
+        unchecked_value,
+        toggled_value,
+    } = values;
+
+    let onset = {
+        let form = form.clone();
+
+        Callback::from(move |checked: bool| {
+            let value = match checked {
+                true => &checked_value,
+                false => &unchecked_value,
+            };
+            form.state_mut().set(value.as_str());
+        })
+    };
+    let onchange = {
+        let form = form.clone();
+        Callback::from(move |_: Event| form.state_mut().set(toggled_value.as_str()))
+    };
+
+    CheckboxBinding {
+        id: meta.id(),
+        name: meta.name(),
+        checked,
+        dirty,
+        valid,
+        touched,
+        aria_invalid: aria.invalid,
+        aria_required: aria.required,
+        aria_describedby: aria.described_by,
+        onset,
+        onchange,
+        onblur: touch_callback(form),
+    }
+}
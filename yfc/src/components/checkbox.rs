@@ -1,6 +1,12 @@
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::{form_state::ValueStateMut, Form};
+use crate::{
+    bindings::{chain, use_checkbox_binding, use_checkbox_binding_for, CheckboxValues},
+    form_value::FormValue,
+    theme::{use_part_classes, Part},
+    Form,
+};
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct CheckboxProps {
@@ -19,37 +25,208 @@ pub fn checkbox(
         ontoggle,
     }: &CheckboxProps,
 ) -> Html {
-    let value = *form.value();
+    let binding = use_checkbox_binding(form);
+    let empty = Classes::new();
+    let classes = use_part_classes(Part::Checkbox, classes, &empty, &empty)
+        .with_state(binding.dirty, binding.valid);
+    let value = !binding.checked;
+    let ontoggle = chain(&binding.onset, ontoggle).reform(move |_| value);
 
-    let ontoggle = {
-        let form = form.clone();
+    // Bulma styles a label around the checkbox, rendered like `ybc::Checkbox`, which has no id,
+    // `aria-*` or blur props
+    #[cfg(feature = "ybc")]
+    let (classes, label_classes) = (Classes::new(), classes!("checkbox", classes));
 
-        ontoggle.reform(move |_| {
-            let value = !value;
-            form.state_mut().set(value.to_string());
-            value
-        })
+    let checkbox = html! {
+        <input
+            id={binding.id}
+            name={binding.name}
+            class={classes}
+            type="checkbox"
+            value={binding.checked.to_string()}
+            onchange={ontoggle}
+            checked={binding.checked}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
+            onblur={binding.onblur}
+        />
     };
 
     #[cfg(feature = "ybc")]
-    {
-        html!(
-            <ybc::Checkbox name="" checked={value} update={ontoggle}/>
-        )
+    let checkbox = html! {
+        <label class={label_classes}>{checkbox}</label>
+    };
+
+    checkbox
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct TriStateCheckboxProps {
+    pub form: Form<Option<bool>>,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Emits the value the checkbox was toggled to
+    #[prop_or_default]
+    pub ontoggle: Callback<Option<bool>>,
+}
+
+/** Returns the value after toggling `value`, cycling through `None`, `Some(true)` and `Some(false)`. */
+fn next_tri_state(value: Option<bool>) -> Option<bool> {
+    match value {
+        None => Some(true),
+        Some(true) => Some(false),
+        Some(false) => None,
     }
+}
+
+/// A checkbox bound to an `Option<bool>`, which is indeterminate while the value is `None`.
+///
+/// Toggling cycles from indeterminate to checked, unchecked and back to indeterminate, so the
+/// value can be cleared again. This is always a native checkbox, as the indeterminate state is a
+/// property of the element.
+#[function_component(TriStateCheckbox)]
+pub fn tri_state_checkbox(
+    TriStateCheckboxProps {
+        form,
+        classes,
+        disabled,
+        ontoggle,
+    }: &TriStateCheckboxProps,
+) -> Html {
+    let value = *form.value();
+    let toggled = next_tri_state(value);
+    let binding = use_checkbox_binding_for(
+        form,
+        CheckboxValues {
+            checked: value == Some(true),
+            checked_value: Some(true).value().into(),
+            unchecked_value: Some(false).value().into(),
+            toggled_value: toggled.value().into(),
+        },
+    );
+    let node_ref = use_node_ref();
+    let empty = Classes::new();
+    let classes = use_part_classes(Part::Checkbox, classes, &empty, &empty)
+        .with_state(binding.dirty, binding.valid);
 
-    #[cfg(not(feature = "ybc"))]
     {
-        html! {
-            <input
-                // id={form.field_name()}
-                class={classes.clone()}
-                type="checkbox"
-                value={value.to_string()}
-                onchange={ontoggle}
-                checked={value}
-                class={classes.clone()}
-             />
-        }
+        let node_ref = node_ref.clone();
+        use_effect_with_deps(
+            move |value| {
+                if let Some(input) = node_ref.cast::<HtmlInputElement>() {
+                    input.set_indeterminate(value.is_none());
+                }
+            },
+            value,
+        );
+    }
+
+    let ontoggle = chain(&binding.onchange, &ontoggle.reform(move |_| toggled));
+
+    html! {
+        <input
+            ref={node_ref}
+            id={binding.id}
+            name={binding.name}
+            class={classes}
+            type="checkbox"
+            checked={binding.checked}
+            disabled={*disabled}
+            onchange={ontoggle}
+            aria-checked={value.is_none().then_some("mixed")}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
+            onblur={binding.onblur}
+        />
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ValueCheckboxProps<T: FormValue> {
+    pub form: Form<T>,
+    /// The value written when the checkbox is checked, the checkbox is checked while the field holds it
+    pub checked_value: AttrValue,
+    /// The value written when the checkbox is unchecked
+    pub unchecked_value: AttrValue,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub ontoggle: Callback<bool>,
+}
+
+/// A checkbox writing `checked_value` or `unchecked_value` into any [`FormValue`], e.g. `"Y"` and `"N"`.
+#[function_component(ValueCheckbox)]
+pub fn value_checkbox<T: FormValue + 'static>(
+    ValueCheckboxProps {
+        form,
+        checked_value,
+        unchecked_value,
+        classes,
+        disabled,
+        ontoggle,
+    }: &ValueCheckboxProps<T>,
+) -> Html {
+    let checked = form.state().value() == checked_value.as_str();
+    let binding = use_checkbox_binding_for(
+        form,
+        CheckboxValues {
+            checked,
+            checked_value: checked_value.clone(),
+            unchecked_value: unchecked_value.clone(),
+            toggled_value: match checked {
+                true => unchecked_value.clone(),
+                false => checked_value.clone(),
+            },
+        },
+    );
+    let empty = Classes::new();
+    let classes = use_part_classes(Part::Checkbox, classes, &empty, &empty)
+        .with_state(binding.dirty, binding.valid);
+    let ontoggle = chain(&binding.onchange, &ontoggle.reform(move |_| !checked));
+
+    // Rendered like `Checkbox` under ybc
+    #[cfg(feature = "ybc")]
+    let (classes, label_classes) = (Classes::new(), classes!("checkbox", classes));
+
+    let checkbox = html! {
+        <input
+            id={binding.id}
+            name={binding.name}
+            class={classes}
+            type="checkbox"
+            value={checked_value.clone()}
+            checked={binding.checked}
+            disabled={*disabled}
+            onchange={ontoggle}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
+            onblur={binding.onblur}
+        />
+    };
+
+    #[cfg(feature = "ybc")]
+    let checkbox = html! {
+        <label class={label_classes}>{checkbox}</label>
+    };
+
+    checkbox
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_tri_state() {
+        assert_eq!(next_tri_state(None), Some(true));
+        assert_eq!(next_tri_state(Some(true)), Some(false));
+        assert_eq!(next_tri_state(Some(false)), None);
     }
 }
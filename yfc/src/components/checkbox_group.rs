@@ -0,0 +1,239 @@
+use std::rc::Rc;
+
+use yew::{html::ChildrenRenderer, prelude::*, virtual_dom::VChild};
+
+use crate::{
+    components::form_field::touch_all_callback,
+    form::Form,
+    hooks::use_described_by,
+    multi_value::MultiValue,
+    theme::{use_part_classes, Part},
+    visit::Visit,
+};
+
+#[derive(Clone, PartialEq)]
+pub enum Checkboxes {
+    Controlled(VChild<CheckboxOption>),
+    Uncontrolled(Html),
+}
+
+impl From<VChild<CheckboxOption>> for Checkboxes {
+    fn from(child: VChild<CheckboxOption>) -> Self {
+        Checkboxes::Controlled(child)
+    }
+}
+
+impl From<Html> for Checkboxes {
+    fn from(child: Html) -> Self {
+        Checkboxes::Uncontrolled(child)
+    }
+}
+
+impl From<Checkboxes> for Html {
+    fn from(checkboxes: Checkboxes) -> Self {
+        match checkboxes {
+            Checkboxes::Controlled(child) => child.into(),
+            Checkboxes::Uncontrolled(child) => child,
+        }
+    }
+}
+
+#[derive(Properties, Clone)]
+pub struct CheckboxGroupProps<T: MultiValue> {
+    pub form: Form<T>,
+    pub children: ChildrenRenderer<Checkboxes>,
+    /// Renders a leading checkbox with this label which selects all or none of the options
+    #[prop_or_default]
+    pub select_all: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub classes_valid: Classes,
+    #[prop_or_default]
+    pub classes_invalid: Classes,
+    /// Added to the options that were checked or unchecked since the form was created
+    #[prop_or_default]
+    pub classes_dirty: Classes,
+    #[prop_or_default]
+    pub ontoggle: Callback<(AttrValue, bool)>,
+}
+
+impl_props_eq!([T: MultiValue] CheckboxGroupProps<T> {
+    form,
+    children,
+    select_all,
+    disabled,
+    classes,
+    classes_valid,
+    classes_invalid,
+    classes_dirty,
+    ontoggle,
+});
+
+/// A group of [`CheckboxOption`]s bound to a list, a set or a [`FlagSet`](crate::FlagSet), an
+/// option is checked when its value is in the model.
+#[function_component(CheckboxGroup)]
+pub fn checkbox_group<T>(
+    CheckboxGroupProps {
+        form,
+        children,
+        select_all,
+        disabled,
+        classes,
+        classes_valid,
+        classes_invalid,
+        classes_dirty,
+        ontoggle,
+    }: &CheckboxGroupProps<T>,
+) -> Html
+where
+    T: MultiValue + 'static,
+    T::State: Visit,
+{
+    let meta = form.meta();
+    let described_by = use_described_by();
+    let onblur = touch_all_callback(form);
+    let (dirty, valid) = {
+        let state = form.state();
+        (T::dirty(&state), T::valid(&state))
+    };
+    let classes = use_part_classes(Part::Group, classes, classes_valid, classes_invalid)
+        .with_state(dirty, valid);
+
+    let onselect = {
+        let form = form.clone();
+
+        ontoggle.reform(move |(value, checked): (AttrValue, bool)| {
+            if form.model().contains_value(&value) != checked {
+                form.toggle_value(&value);
+            }
+            (value, checked)
+        })
+    };
+
+    let options = children
+        .iter()
+        .filter_map(|checkbox| match checkbox {
+            Checkboxes::Controlled(checkbox) => Some(checkbox.props.value.to_string()),
+            Checkboxes::Uncontrolled(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let model = form.model();
+    let all_checked = options.iter().all(|value| model.contains_value(value));
+
+    let select_all = select_all.as_ref().map(|label| {
+        let form = form.clone();
+        let onselect = Callback::from(move |(_, checked): (AttrValue, bool)| match checked {
+            true => form.select_all(&options),
+            false => form.select_none(),
+        });
+
+        html! {
+            <CheckboxOption value="" checked={all_checked} disabled={*disabled} {onselect} onblur={onblur.clone()}>
+                {label}
+            </CheckboxOption>
+        }
+    });
+
+    html! {
+        <div
+            id={meta.id()}
+            class={classes}
+            role="group"
+            aria-invalid={(!valid).then_some("true")}
+            aria-required={meta.required().then_some("true")}
+            aria-describedby={described_by}
+        >
+            {select_all}
+            { for children.iter().map(|checkbox| {
+                match checkbox {
+                    Checkboxes::Controlled(mut checkbox) => {
+                        let props = Rc::make_mut(&mut checkbox.props);
+                        props.checked = model.contains_value(&props.value);
+                        props.name = meta.name();
+                        props.disabled |= *disabled;
+                        props.onselect = onselect.clone();
+                        props.onblur = onblur.clone();
+                        if T::value_dirty(&form.state(), &props.value) {
+                            props.classes.extend(classes_dirty.clone());
+                        }
+                        checkbox.into()
+                    },
+                    Checkboxes::Uncontrolled(checkbox) => {
+                        checkbox
+                    }
+                }
+            })}
+        </div>
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CheckboxOptionProps {
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub children: Option<Children>,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    checked: bool,
+    #[prop_or_default]
+    name: Option<AttrValue>,
+    #[prop_or_default]
+    onselect: Callback<(AttrValue, bool)>,
+    #[prop_or_default]
+    onblur: Callback<FocusEvent>,
+}
+
+#[function_component(CheckboxOption)]
+pub fn checkbox_option(
+    CheckboxOptionProps {
+        value,
+        children,
+        classes,
+        disabled,
+        checked,
+        name,
+        onselect,
+        onblur,
+        ..
+    }: &CheckboxOptionProps,
+) -> Html {
+    let label = match children {
+        Some(children) => children.iter().collect::<Html>(),
+        None => html! {{value}},
+    };
+
+    // The classes of the props are applied to the label, the theme styles the input itself
+    let empty = Classes::new();
+    let input_classes = use_part_classes(Part::Checkbox, &empty, &empty, &empty).classes;
+    let onchange = {
+        let value = value.clone();
+        let checked = *checked;
+        onselect.reform(move |_: Event| (value.clone(), !checked))
+    };
+
+    // Bulma styles the label, as `ybc::Checkbox` renders it, which has no blur prop
+    #[cfg(feature = "ybc")]
+    let classes = classes!("checkbox", classes.clone());
+
+    html! {
+        <label class={classes.clone()}>
+            <input
+                class={input_classes}
+                type="checkbox"
+                name={name.clone()}
+                value={value.clone()}
+                checked={*checked}
+                disabled={*disabled}
+                {onchange}
+                onblur={onblur.clone()}
+            />
+            {label}
+        </label>
+    }
+}
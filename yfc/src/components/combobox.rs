@@ -0,0 +1,405 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use web_sys::{HtmlInputElement, InputEvent, KeyboardEvent};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    bindings::{chain, use_input_binding, InputBinding},
+    form::Form,
+    form_value::FormValue,
+    theme::{use_part_classes, Part},
+};
+
+/// The future returned by a [`SuggestionProvider`].
+pub type SuggestionFuture = Pin<Box<dyn Future<Output = Vec<AttrValue>>>>;
+
+/// Loads the suggestions of a [`Combobox`] for the typed text, e.g. from a server.
+///
+/// Compared by reference, so create it once, e.g. with `use_memo`.
+#[derive(Clone)]
+pub struct SuggestionProvider(Rc<dyn Fn(String) -> SuggestionFuture>);
+
+impl SuggestionProvider {
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<AttrValue>> + 'static,
+    {
+        Self(Rc::new(move |query| Box::pin(load(query))))
+    }
+
+    pub fn load(&self, query: String) -> SuggestionFuture {
+        (self.0)(query)
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for SuggestionProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SuggestionProvider")
+    }
+}
+
+/** Returns the options containing `query`, ignoring case. */
+fn filter_options(options: &[AttrValue], query: &str) -> Vec<AttrValue> {
+    let query = query.to_lowercase();
+
+    options
+        .iter()
+        .filter(|option| option.to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+/// Everything needed to render a combobox, returned by [`use_combobox`].
+///
+/// The input gets `role="combobox"` and the suggestions are the options of the listbox with
+/// [`ComboboxBinding::listbox_id`], each with the id of [`ComboboxBinding::option_id`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboboxBinding {
+    /// The binding of the text input, use `oninput` and `onblur` of the combobox instead
+    pub input: InputBinding,
+    pub listbox_id: AttrValue,
+    /// The options containing the typed text, or the suggestions of the provider once loaded
+    pub suggestions: Vec<AttrValue>,
+    pub expanded: bool,
+    /// The index of the suggestion selected with the arrow keys
+    pub active: Option<usize>,
+    /// The id of the active option, for `aria-activedescendant`
+    pub active_descendant: Option<AttrValue>,
+    /// Whether the provider is loading suggestions
+    pub loading: bool,
+    pub oninput: Callback<InputEvent>,
+    /// Moves through the suggestions with the arrow keys, `Enter` picks one and `Escape` closes them
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// Closes the suggestions and marks the field as touched
+    pub onblur: Callback<FocusEvent>,
+    /// Writes a suggestion into the field and closes the suggestions
+    pub onselect: Callback<AttrValue>,
+}
+
+impl ComboboxBinding {
+    pub fn option_id(&self, index: usize) -> AttrValue {
+        format!("{}-{index}", self.listbox_id).into()
+    }
+}
+
+/// Binds a typeahead to a field, suggesting the `options` containing the typed text or the
+/// results of `provider`.
+///
+/// The id of the listbox is derived from the id of the input, which `id` overrides.
+///
+/// # Panics
+///
+/// When the form has no id and no `id` is given.
+#[hook]
+pub fn use_combobox<T>(
+    form: &Form<T>,
+    id: Option<&AttrValue>,
+    options: &[AttrValue],
+    provider: Option<&SuggestionProvider>,
+) -> ComboboxBinding
+where
+    T: FormValue + 'static,
+{
+    let mut input = use_input_binding(form);
+    let id = id
+        .cloned()
+        .or_else(|| input.id.clone())
+        .expect("a combobox needs an `id` for a form without one");
+    input.id = Some(id.clone());
+    let expanded = use_state_eq(|| false);
+    let active = use_state_eq(|| None::<usize>);
+    let loaded = use_state(|| None::<Vec<AttrValue>>);
+    let loading = use_state_eq(|| false);
+    // Results of earlier requests that arrive late are dropped
+    let request = use_mut_ref(|| 0usize);
+
+    let listbox_id = AttrValue::from(format!("{id}-listbox"));
+    let suggestions = match &*loaded {
+        Some(loaded) => loaded.clone(),
+        None => filter_options(options, &input.value),
+    };
+    let current = (*active).filter(|active| *active < suggestions.len());
+
+    let onselect = {
+        let onset = input.onset.clone();
+        let expanded = expanded.clone();
+        let active = active.clone();
+
+        Callback::from(move |value: AttrValue| {
+            onset.emit(value);
+            expanded.set(false);
+            active.set(None);
+        })
+    };
+
+    let oninput = {
+        let base = input.oninput.clone();
+        let provider = provider.cloned();
+        let expanded = expanded.clone();
+        let active = active.clone();
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+
+        Callback::from(move |e: InputEvent| {
+            let query = e
+                .target_dyn_into::<HtmlInputElement>()
+                .map(|input| input.value());
+            base.emit(e);
+            expanded.set(true);
+            active.set(None);
+
+            let (Some(provider), Some(query)) = (&provider, query) else {
+                return;
+            };
+
+            let id = {
+                let mut request = request.borrow_mut();
+                *request += 1;
+                *request
+            };
+            let suggestions = provider.load(query);
+            let (request, loaded, loading) = (request.clone(), loaded.clone(), loading.clone());
+
+            loading.set(true);
+            spawn_local(async move {
+                let suggestions = suggestions.await;
+
+                if *request.borrow() == id {
+                    loaded.set(Some(suggestions));
+                    loading.set(false);
+                }
+            });
+        })
+    };
+
+    let onkeydown = {
+        let suggestions = suggestions.clone();
+        let is_expanded = *expanded;
+        let expanded = expanded.clone();
+        let active = active.clone();
+        let onselect = onselect.clone();
+
+        Callback::from(move |e: KeyboardEvent| {
+            let count = suggestions.len();
+
+            match e.key().as_str() {
+                "ArrowDown" | "ArrowUp" if count > 0 => {
+                    e.prevent_default();
+                    let next = match (e.key().as_str(), current) {
+                        ("ArrowDown", Some(current)) => (current + 1) % count,
+                        ("ArrowDown", None) => 0,
+                        (_, Some(0) | None) => count - 1,
+                        (_, Some(current)) => current - 1,
+                    };
+                    expanded.set(true);
+                    active.set(Some(next));
+                }
+                "Enter" if is_expanded => {
+                    if let Some(current) = current {
+                        e.prevent_default();
+                        onselect.emit(suggestions[current].clone());
+                    }
+                }
+                "Escape" if is_expanded => {
+                    expanded.set(false);
+                    active.set(None);
+                }
+                _ => {}
+            }
+        })
+    };
+
+    let onblur = {
+        let expanded = expanded.clone();
+        let active = active.clone();
+
+        chain(
+            &Callback::from(move |_: FocusEvent| {
+                expanded.set(false);
+                active.set(None);
+            }),
+            &input.onblur,
+        )
+    };
+
+    let expanded = *expanded && !suggestions.is_empty();
+    let active_descendant = current
+        .filter(|_| expanded)
+        .map(|current| AttrValue::from(format!("{listbox_id}-{current}")));
+
+    ComboboxBinding {
+        input,
+        listbox_id,
+        suggestions,
+        expanded,
+        active: current,
+        active_descendant,
+        loading: *loading,
+        oninput,
+        onkeydown,
+        onblur,
+        onselect,
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ComboboxProps<T: FormValue> {
+    pub form: Form<T>,
+    /// Overrides the id of the input, which the id of the listbox is derived from, required for a
+    /// form without an id
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// The static suggestions, filtered by the typed text
+    #[prop_or_default]
+    pub options: Vec<AttrValue>,
+    /// Loads the suggestions instead of filtering `options`
+    #[prop_or_default]
+    pub provider: Option<SuggestionProvider>,
+    /// Renders a native `datalist` instead of the listbox, leaving the suggestions to the browser
+    #[prop_or_default]
+    pub datalist: bool,
+    /// Overrides the placeholder of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Classes that are applied when the field is dirty and invalid
+    #[prop_or_default]
+    pub classes_invalid: Classes,
+    /// Classes that are applied when the field is dirty and valid
+    #[prop_or_default]
+    pub classes_valid: Classes,
+    #[prop_or_default]
+    pub classes_listbox: Classes,
+    /// Called with the suggestion picked from the listbox
+    #[prop_or_default]
+    pub onselect: Callback<AttrValue>,
+}
+
+/// A text input suggesting values while typing, next to [`Select`](crate::Select) for open-ended
+/// values.
+///
+/// Build other markup on [`use_combobox`] for a headless combobox.
+#[function_component(Combobox)]
+pub fn combobox<T: FormValue + 'static>(
+    ComboboxProps {
+        form,
+        id,
+        options,
+        provider,
+        datalist,
+        placeholder,
+        disabled,
+        classes,
+        classes_invalid,
+        classes_valid,
+        classes_listbox,
+        onselect,
+    }: &ComboboxProps<T>,
+) -> Html {
+    let binding = use_combobox(form, id.as_ref(), options, provider.as_ref());
+    let placeholder = placeholder
+        .clone()
+        .or_else(|| binding.input.placeholder.clone());
+    let classes = use_part_classes(Part::Input, classes, classes_valid, classes_invalid)
+        .with_state(binding.input.dirty, binding.input.valid);
+
+    // Styled like a text `Input` under ybc
+    #[cfg(feature = "ybc")]
+    let classes = classes!(classes, "input");
+
+    if *datalist {
+        return html! {
+            <>
+                <input
+                    id={binding.input.id.clone()}
+                    name={binding.input.name.clone()}
+                    class={classes}
+                    type="text"
+                    list={binding.listbox_id.clone()}
+                    {placeholder}
+                    value={binding.input.value.clone()}
+                    aria-invalid={binding.input.aria_invalid.clone()}
+                    aria-required={binding.input.aria_required.clone()}
+                    aria-describedby={binding.input.aria_describedby.clone()}
+                    oninput={binding.oninput.clone()}
+                    onblur={binding.input.onblur.clone()}
+                    disabled={*disabled}
+                />
+                <datalist id={binding.listbox_id.clone()}>
+                    {for binding.suggestions.iter().map(|suggestion| html! {
+                        <option value={suggestion.clone()}/>
+                    })}
+                </datalist>
+            </>
+        };
+    }
+
+    let onselect = chain(&binding.onselect, onselect);
+
+    html! {
+        <div>
+            <input
+                id={binding.input.id.clone()}
+                name={binding.input.name.clone()}
+                class={classes}
+                type="text"
+                role="combobox"
+                autocomplete="off"
+                aria-autocomplete="list"
+                aria-expanded={binding.expanded.to_string()}
+                aria-controls={binding.listbox_id.clone()}
+                aria-activedescendant={binding.active_descendant.clone()}
+                aria-busy={binding.loading.then_some("true")}
+                {placeholder}
+                value={binding.input.value.clone()}
+                aria-invalid={binding.input.aria_invalid.clone()}
+                aria-required={binding.input.aria_required.clone()}
+                aria-describedby={binding.input.aria_describedby.clone()}
+                oninput={binding.oninput.clone()}
+                onkeydown={binding.onkeydown.clone()}
+                onblur={binding.onblur.clone()}
+                disabled={*disabled}
+            />
+            <ul
+                id={binding.listbox_id.clone()}
+                class={classes_listbox.clone()}
+                role="listbox"
+                hidden={!binding.expanded}
+            >
+                {for binding.suggestions.iter().enumerate().map(|(index, suggestion)| {
+                    // Picked on mousedown, as a click would blur the input and close the list first
+                    let onmousedown = {
+                        let onselect = onselect.clone();
+                        let suggestion = suggestion.clone();
+
+                        Callback::from(move |e: MouseEvent| {
+                            e.prevent_default();
+                            onselect.emit(suggestion.clone());
+                        })
+                    };
+
+                    html! {
+                        <li
+                            id={binding.option_id(index)}
+                            role="option"
+                            aria-selected={(binding.active == Some(index)).to_string()}
+                            {onmousedown}
+                        >
+                            {suggestion}
+                        </li>
+                    }
+                })}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,191 @@
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+use yew::prelude::*;
+
+use crate::{
+    dyn_field::FieldLookup,
+    field::Field,
+    form::Form,
+    meta::FieldMeta,
+    model::Model,
+    path::FieldPath,
+    theme::{use_part_classes, Part},
+    visit::Visit,
+};
+
+/// An invalid field listed by an [`ErrorSummary`].
+#[derive(Debug, Clone, PartialEq)]
+struct ErrorEntry {
+    id: AttrValue,
+    text: AttrValue,
+}
+
+/** Returns the invalid fields of the form in declaration order, described by their message, label or else name. */
+fn error_entries<T>(form: &Form<T>) -> Vec<ErrorEntry>
+where
+    T: Model + FieldLookup,
+    T::State: Visit,
+{
+    let mut entries = vec![];
+
+    form.state().visit(&mut |path: &FieldPath, field: &Field| {
+        if field.valid() {
+            return;
+        }
+
+        // The paths use the identifiers of the fields, their metas the names the controls got
+        let meta = T::lookup_meta(path.segments(), form.meta().clone())
+            .unwrap_or_else(|| FieldMeta::new(path.to_string()).nested_in(form.meta()));
+        #[cfg(feature = "validator")]
+        let message = (!field.message().is_empty()).then(|| field.message().to_owned());
+        #[cfg(not(feature = "validator"))]
+        let message: Option<String> = None;
+
+        entries.push(ErrorEntry {
+            id: meta.id().unwrap_or_default(),
+            text: message
+                .map(AttrValue::from)
+                .or_else(|| meta.label().cloned())
+                .or_else(|| meta.name())
+                .unwrap_or_default(),
+        });
+    });
+
+    entries
+}
+
+/** Returns the control to focus for the element, the checked or else first control of a group. */
+fn focus_target(element: Element) -> Option<Element> {
+    match element.matches("[role=group], [role=radiogroup]") {
+        Ok(true) => element
+            .query_selector("input:checked:enabled")
+            .ok()
+            .flatten()
+            .or_else(|| element.query_selector("input:enabled").ok().flatten()),
+        _ => Some(element),
+    }
+}
+
+/** Focuses the control with `id`, or the group labelled by its label, returns whether there was one to focus. */
+fn focus_by_id(id: &str) -> bool {
+    let element = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| {
+            document.get_element_by_id(id).or_else(|| {
+                let selector = format!("[role=radiogroup][aria-labelledby=\"{id}-label\"]");
+                document.query_selector(&selector).ok().flatten()
+            })
+        })
+        .and_then(focus_target)
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+    match element {
+        Some(element) => element.focus().is_ok(),
+        None => false,
+    }
+}
+
+#[derive(Properties, Clone)]
+pub struct ErrorSummaryProps<T: Model> {
+    pub form: Form<T>,
+    /// Counts the submits of the form, bump it on every submit
+    #[prop_or_default]
+    pub submitted: u32,
+    #[prop_or_else(|| "There is a problem".into())]
+    pub title: AttrValue,
+    /// The id of the summary, its title gets the id with `-title` appended
+    #[prop_or_else(|| "error-summary".into())]
+    pub id: AttrValue,
+    #[prop_or_default]
+    pub classes: Classes,
+}
+
+impl_props_eq!([T: Model] ErrorSummaryProps<T> {
+    form,
+    submitted,
+    title,
+    id,
+    classes,
+});
+
+/// Lists every invalid field of the form with a link focusing its control, rendered at the top of
+/// a form after a failed submit.
+///
+/// The links point to the ids of the fields, e.g. `items-3-price`, which the components give their
+/// controls, or to the first control of a [`RadioGroup`](crate::RadioGroup) or
+/// [`CheckboxGroup`](crate::CheckboxGroup). The text of a link is the validator message of the
+/// field, or else its label or name.
+/// Nothing is rendered before the first submit, counted by `submitted`, or while all fields are
+/// valid, so errors don't show up while typing. After every submit that finds errors the summary
+/// takes the focus, so screen readers announce it.
+#[function_component(ErrorSummary)]
+pub fn error_summary<T>(
+    ErrorSummaryProps {
+        form,
+        submitted,
+        title,
+        id,
+        classes,
+    }: &ErrorSummaryProps<T>,
+) -> Html
+where
+    T: Model + FieldLookup + 'static,
+    T::State: Visit,
+{
+    let node_ref = use_node_ref();
+    let entries = error_entries(form);
+    let empty = Classes::new();
+    let classes = use_part_classes(Part::ErrorSummary, classes, &empty, &empty).classes;
+
+    {
+        let node_ref = node_ref.clone();
+        let has_errors = !entries.is_empty();
+
+        use_effect_with_deps(
+            move |submitted| {
+                if *submitted > 0 && has_errors {
+                    if let Some(summary) = node_ref.cast::<HtmlElement>() {
+                        let _ = summary.focus();
+                    }
+                }
+            },
+            *submitted,
+        );
+    }
+
+    if *submitted == 0 || entries.is_empty() {
+        return html! {};
+    }
+
+    let title_id = format!("{id}-title");
+
+    html! {
+        <div
+            id={id.clone()}
+            class={classes}
+            role="alert"
+            tabindex="-1"
+            aria-labelledby={title_id.clone()}
+            ref={node_ref}
+        >
+            <h2 id={title_id}>{title}</h2>
+            <ul>
+                {for entries.into_iter().map(|ErrorEntry { id, text }| {
+                    // Focused directly, as following the link only scrolls to the control
+                    let onclick = {
+                        let id = id.clone();
+
+                        Callback::from(move |e: MouseEvent| {
+                            if focus_by_id(&id) {
+                                e.prevent_default();
+                            }
+                        })
+                    };
+
+                    html! {
+                        <li><a href={format!("#{id}")} {onclick}>{text}</a></li>
+                    }
+                })}
+            </ul>
+        </div>
+    }
+}
@@ -1,7 +1,13 @@
 use web_sys::{HtmlInputElement, InputEvent};
 use yew::prelude::*;
 
-use crate::{form::Form, form_state::ValueStateMut};
+use crate::{
+    components::form_field::{touch_callback, use_aria},
+    files::{FileHandle, Files},
+    form::Form,
+    form_state::StateMut,
+    theme::{use_part_classes, Part},
+};
 
 pub enum FileMessage {
     OnInput(InputEvent),
@@ -9,21 +15,29 @@ pub enum FileMessage {
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct FilePropeties {
-    pub form: Form<String>,
-    pub field_name: AttrValue,
+    pub form: Form<Files>,
+    /// Overrides the name of the field's [`FieldMeta`](crate::FieldMeta), also used as the id
+    #[prop_or_default]
+    pub field_name: Option<AttrValue>,
     #[prop_or_default]
     pub disabled: bool,
     #[prop_or_default]
     pub multiple: bool,
+    /// Also checked when files are picked, as browsers still allow picking other files
     #[prop_or_default]
     pub accept: AttrValue,
+    /// The maximum size of each file in bytes, larger files make the field invalid
+    #[prop_or_default]
+    pub max_size: Option<u64>,
     #[prop_or_default]
     pub capture: AttrValue,
     #[prop_or_default]
     pub classes: Classes,
-    #[prop_or_else(|| "is-invalid".into())]
+    /// Classes that are applied when the field is dirty and invalid
+    #[prop_or_default]
     pub classes_invalid: Classes,
-    #[prop_or_else(|| "is-valid".into())]
+    /// Classes that are applied when the field is dirty and valid
+    #[prop_or_default]
     pub classes_valid: Classes,
     #[prop_or_default]
     pub oninput: Callback<InputEvent>,
@@ -37,6 +51,7 @@ pub fn file(
         disabled,
         multiple,
         accept,
+        max_size,
         capture,
         classes,
         classes_valid,
@@ -45,19 +60,41 @@ pub fn file(
         ..
     }: &FilePropeties,
 ) -> Html {
-    let classes = classes!(
-        classes.clone(),
-        form.state().dirty().then(|| match form.state().valid() {
-            true => classes_valid.clone(),
-            false => classes_invalid.clone(),
-        })
-    );
+    let meta = form.meta();
+    let aria = use_aria(form);
+    let onblur = touch_callback(form);
+    let (id, name) = match field_name {
+        Some(field_name) => (Some(field_name.clone()), Some(field_name.clone())),
+        None => (meta.id(), meta.name()),
+    };
+    let classes = use_part_classes(Part::File, classes, classes_valid, classes_invalid)
+        .with_state(form.state().dirty(), form.state().valid());
     let oninput = oninput.reform({
         let form = form.clone();
+        let accept = accept.clone();
+        let max_size = *max_size;
 
         move |e: InputEvent| {
-            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                form.state_mut().set(input.value());
+            if let Some(list) = e
+                .target_dyn_into::<HtmlInputElement>()
+                .and_then(|input| input.files())
+            {
+                let files = (0..list.length())
+                    .filter_map(|index| list.get(index))
+                    .map(FileHandle::from)
+                    .collect::<Files>();
+                let result = files.validate_accept(&accept).and_then(|_| match max_size {
+                    Some(max_size) => files.validate_max_size(max_size),
+                    None => Ok(()),
+                });
+
+                let mut state = form.state_mut();
+                state.set_files(files);
+
+                let (_, mut field) = state.split();
+                field.set_valid(result.is_ok());
+                #[cfg(feature = "validator")]
+                field.set_message(result.err().map(|e| e.to_string()).unwrap_or_default());
             }
 
             e
@@ -66,14 +103,18 @@ pub fn file(
 
     html! {
         <input
-            id={field_name}
+            {id}
             type="file"
-            name={field_name}
+            {name}
             {accept}
             disabled={*disabled}
             multiple={*multiple}
             class={classes}
+            aria-invalid={aria.invalid}
+            aria-required={aria.required}
+            aria-describedby={aria.described_by}
             {oninput}
+            {onblur}
             {capture}
         />
     }
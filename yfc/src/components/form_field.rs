@@ -0,0 +1,209 @@
+#[cfg(feature = "ybc")]
+use ybc;
+use yew::{prelude::*, virtual_dom::VNode};
+
+use crate::{
+    components::Label,
+    field::Field,
+    form::Form,
+    form_state::{StateMut, StateProvider},
+    form_value::FormValue,
+    hooks::use_described_by,
+    path::FieldPath,
+    theme::{use_part_classes, Part},
+    visit::Visit,
+};
+
+/// Provided by [`FormField`] to its control, read with [`use_described_by`](crate::hooks::use_described_by).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FieldDescription {
+    pub described_by: Option<AttrValue>,
+}
+
+/// The `aria-*` attributes of a control, derived from the state and metadata of its field.
+pub(crate) struct Aria {
+    pub invalid: Option<AttrValue>,
+    pub required: Option<AttrValue>,
+    pub described_by: Option<AttrValue>,
+}
+
+#[hook]
+pub(crate) fn use_aria<T>(form: &Form<T>) -> Aria
+where
+    T: StateProvider<State = Field> + 'static,
+{
+    let described_by = use_described_by();
+
+    Aria {
+        invalid: (!form.state().valid()).then(|| "true".into()),
+        required: form.meta().required().then(|| "true".into()),
+        described_by,
+    }
+}
+
+/** Marks the field as touched when the control loses focus, used by all the controls. */
+pub(crate) fn touch_callback<T>(form: &Form<T>) -> Callback<FocusEvent>
+where
+    T: StateProvider<State = Field> + 'static,
+{
+    let form = form.clone();
+
+    Callback::from(move |_| {
+        if !form.state().touched() {
+            let (_, mut field) = form.state_mut().split();
+            field.set_touched(true);
+        }
+    })
+}
+
+/** Marks all the fields of a list or set as touched when one of its controls loses focus. */
+pub(crate) fn touch_all_callback<T>(form: &Form<T>) -> Callback<FocusEvent>
+where
+    T: StateProvider + 'static,
+    T::State: Visit,
+{
+    let form = form.clone();
+
+    Callback::from(move |_| {
+        let mut touched = true;
+        form.state()
+            .visit(&mut |_: &FieldPath, field: &Field| touched &= field.touched());
+
+        if !touched {
+            form.visit_fields_mut(&mut |_: &FieldPath, field: &mut Field| field.set_touched(true));
+        }
+    })
+}
+
+#[derive(Properties, Clone)]
+pub struct FormFieldProps<T: FormValue> {
+    pub form: Form<T>,
+    /// The control of the field, elements get `aria-describedby` set directly
+    #[prop_or_default]
+    pub children: Children,
+    /// Overrides the label of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// Overrides the help text of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub classes_error: Classes,
+    #[prop_or_default]
+    pub classes_help: Classes,
+}
+
+impl_props_eq!([T: FormValue] FormFieldProps<T> {
+    form,
+    children,
+    label,
+    help,
+    classes,
+    classes_error,
+    classes_help,
+});
+
+/// Wraps a control with the label, error message and help text of its field.
+///
+/// The error is the validator message of the field, shown once it's invalid and touched.
+#[function_component(FormField)]
+pub fn form_field<T: FormValue + 'static>(
+    FormFieldProps {
+        form,
+        children,
+        label,
+        help,
+        classes,
+        classes_error,
+        classes_help,
+    }: &FormFieldProps<T>,
+) -> Html {
+    let meta = form.meta();
+    let label = label.clone().or_else(|| meta.label().cloned());
+    let help = help.clone().or_else(|| meta.help().cloned());
+    let empty = Classes::new();
+    let classes_error = use_part_classes(Part::Error, classes_error, &empty, &empty).classes;
+    let classes_help = use_part_classes(Part::Help, classes_help, &empty, &empty).classes;
+
+    #[cfg(feature = "validator")]
+    let error = {
+        let field = form.state();
+        (!field.valid() && field.touched() && !field.message().is_empty())
+            .then(|| AttrValue::from(field.message().to_owned()))
+    };
+    #[cfg(not(feature = "validator"))]
+    let error: Option<AttrValue> = None;
+
+    // Without an id, e.g. for the form of a whole model, nothing can refer to the error or help
+    let error_id = meta.id().map(|id| AttrValue::from(format!("{id}-error")));
+    let help_id = meta.id().map(|id| AttrValue::from(format!("{id}-help")));
+    let described_by = [
+        error.as_ref().and(error_id.as_ref()),
+        help.as_ref().and(help_id.as_ref()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(AttrValue::as_str)
+    .collect::<Vec<_>>();
+    let described_by = (!described_by.is_empty()).then(|| AttrValue::from(described_by.join(" ")));
+
+    let control = children
+        .iter()
+        .map(|mut child| {
+            if let (VNode::VTag(tag), Some(described_by)) = (&mut child, &described_by) {
+                tag.add_attribute("aria-describedby", described_by.clone());
+            }
+            child
+        })
+        .collect::<Html>();
+    let control = html! {
+        <ContextProvider<FieldDescription> context={FieldDescription { described_by }}>
+            {control}
+        </ContextProvider<FieldDescription>>
+    };
+
+    #[cfg(feature = "ybc")]
+    {
+        html! {
+            <ybc::Field classes={classes.clone()}>
+                if let Some(label) = label {
+                    <Label<T> form={form.clone()}>{label}</Label<T>>
+                }
+                <ybc::Control>
+                    {control}
+                </ybc::Control>
+                // `ybc::Help` has no id, so a wrapper is referred to by `aria-describedby`
+                if let Some(error) = error {
+                    <div id={error_id}>
+                        <ybc::Help classes={classes!("is-danger", classes_error)}>{error}</ybc::Help>
+                    </div>
+                }
+                if let Some(help) = help {
+                    <div id={help_id}>
+                        <ybc::Help classes={classes_help}>{help}</ybc::Help>
+                    </div>
+                }
+            </ybc::Field>
+        }
+    }
+
+    #[cfg(not(feature = "ybc"))]
+    {
+        html! {
+            <div class={classes.clone()}>
+                if let Some(label) = label {
+                    <Label<T> form={form.clone()}>{label}</Label<T>>
+                }
+                {control}
+                if let Some(error) = error {
+                    <p id={error_id} class={classes_error}>{error}</p>
+                }
+                if let Some(help) = help {
+                    <p id={help_id} class={classes_help}>{help}</p>
+                }
+            </div>
+        }
+    }
+}
@@ -1,13 +1,20 @@
+use std::str::FromStr;
+
 use strum::IntoStaticStr;
 use web_sys::{HtmlInputElement, InputEvent};
-#[cfg(feature = "ybc")]
-use ybc;
 use yew::{
     html::{ImplicitClone, IntoPropValue},
     prelude::*,
 };
 
-use crate::{form::Form, form_state::ValueStateMut, form_value::FormValue};
+use crate::{
+    bindings::{chain, use_input_binding},
+    form::Form,
+    form_value::{FormValue, ValueWrapper},
+    mask::{chars_to_utf16, utf16_to_chars, DynMask},
+    theme::{use_part_classes, Part},
+    transform::Transform,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, IntoStaticStr)]
 #[strum(serialize_all = "snake_case")]
@@ -17,7 +24,17 @@ pub enum InputType {
     Email,
     Tel,
     Url,
+    Search,
+    Number,
+    Range,
     Date,
+    Time,
+    #[strum(serialize = "datetime-local")]
+    DatetimeLocal,
+    Month,
+    Week,
+    Color,
+    Hidden,
 }
 
 impl ImplicitClone for InputType {}
@@ -30,26 +47,68 @@ impl IntoPropValue<Option<AttrValue>> for InputType {
 
 #[cfg(feature = "ybc")]
 impl InputType {
-    fn ybc_type(&self) -> Option<ybc::InputType> {
-        match self {
-            InputType::Text => Some(ybc::InputType::Text),
-            InputType::Password => Some(ybc::InputType::Password),
-            InputType::Email => Some(ybc::InputType::Email),
-            InputType::Tel => Some(ybc::InputType::Tel),
-            _ => None,
-        }
+    /// Returns whether Bulma styles the type with the `input` class, it has no style for sliders.
+    ///
+    /// Inputs are rendered natively with the classes `ybc::Input` would add, as it has no id,
+    /// `aria-*`, blur or ref props. Its `ybc::InputType` also only has text, password, email and
+    /// tel, it lacks url, search, number, range, date, time, datetime-local, month, week, color
+    /// and hidden.
+    pub(crate) fn is_bulma_input(&self) -> bool {
+        !matches!(self, InputType::Range | InputType::Hidden)
+    }
+}
+
+/// A [`FormValue`] an [`Input`] can be bound to, which picks its type when none is given.
+///
+/// Text that doesn't parse, e.g. the empty value a `number` input has for partial input like
+/// `-`, leaves the model unchanged, [`NumberInput`](crate::NumberInput) also marks it invalid.
+pub trait InputValue: FormValue {
+    /** The type of [`Input`] used when none is given, e.g. `number` for numbers. */
+    fn default_input_type() -> InputType {
+        InputType::Text
+    }
+}
+
+macro_rules! impl_input_value {
+    ($input_type:ident: $($t:ty),*) => {
+        $(
+            impl InputValue for $t {
+                fn default_input_type() -> InputType {
+                    InputType::$input_type
+                }
+            }
+        )*
+    };
+}
+
+impl_input_value!(
+    Number: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+impl_input_value!(Text: bool, char, String);
+
+impl<T> InputValue for Option<T>
+where
+    T: InputValue,
+    Option<T>: FormValue,
+{
+    fn default_input_type() -> InputType {
+        T::default_input_type()
     }
 }
 
+impl<T> InputValue for ValueWrapper<T> where T: FromStr + ToString + PartialEq + 'static {}
+
 #[derive(Properties, PartialEq, Clone)]
-pub struct InputProps<T: FormValue> {
+pub struct InputProps<T: InputValue> {
     pub form: Form<T>,
     #[prop_or_default]
     pub autocomplete: bool,
-    #[prop_or(InputType::Text)]
-    pub input_type: InputType,
+    /// Defaults to the [`InputValue::default_input_type`] of the value
+    #[prop_or_default]
+    pub input_type: Option<InputType>,
+    /// Overrides the placeholder of the field's [`FieldMeta`](crate::FieldMeta)
     #[prop_or_default]
-    pub placeholder: AttrValue,
+    pub placeholder: Option<AttrValue>,
     #[prop_or_default]
     pub disabled: bool,
     #[prop_or_default]
@@ -60,12 +119,45 @@ pub struct InputProps<T: FormValue> {
     /// Classes that are applied when the field is dirty and valid
     #[prop_or_default]
     pub classes_valid: Classes,
+    /// Overrides the mask of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub mask: Option<DynMask>,
+    /// Overrides the transform of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub transform: Option<Transform>,
     #[prop_or_default]
     pub oninput: Callback<InputEvent>,
 }
 
+/** Masks the typed value and writes the stored value into the form, keeping the caret after the typed characters. */
+fn masked_oninput(
+    mask: DynMask,
+    onset: Callback<AttrValue>,
+    caret: std::rc::Rc<std::cell::RefCell<Option<u32>>>,
+) -> Callback<InputEvent> {
+    Callback::from(move |e: InputEvent| {
+        let Some(input) = e.target_dyn_into::<HtmlInputElement>() else {
+            return;
+        };
+
+        let display = input.value();
+        let position = match input.selection_start() {
+            Ok(Some(position)) => utf16_to_chars(&display, position as usize),
+            _ => display.chars().count(),
+        };
+        let masked = mask.input(&display, position);
+        let position = chars_to_utf16(&masked.display, masked.caret) as u32;
+
+        input.set_value(&masked.display);
+        let _ = input.set_selection_range(position, position);
+        // The value is set again when rendering, which moves the caret to the end
+        *caret.borrow_mut() = Some(position);
+        onset.emit(masked.stored.into());
+    })
+}
+
 #[function_component(Input)]
-pub fn input<T: FormValue + 'static>(
+pub fn input<T: InputValue + 'static>(
     InputProps {
         autocomplete,
         input_type,
@@ -75,60 +167,74 @@ pub fn input<T: FormValue + 'static>(
         classes,
         classes_invalid,
         classes_valid,
+        mask,
+        transform,
         oninput,
     }: &InputProps<T>,
 ) -> Html {
-    let classes = classes!(
-        classes.clone(),
-        form.state().dirty().then(|| match form.state().valid() {
-            true => classes_valid.clone(),
-            false => classes_invalid.clone(),
-        })
-    );
-
-    let oninput = {
-        let form = form.clone();
-
-        oninput.reform(move |e: InputEvent| {
-            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                form.state_mut().set(input.value());
-            }
-            e
-        })
+    let input_type = input_type.unwrap_or_else(T::default_input_type);
+    let form = match transform {
+        Some(transform) => form
+            .clone()
+            .map_meta(|meta| meta.with_transform(transform.clone())),
+        None => form.clone(),
+    };
+    let binding = use_input_binding(&form);
+    let placeholder = placeholder.clone().or_else(|| binding.placeholder.clone());
+    let part = match input_type {
+        InputType::Range => Part::Range,
+        _ => Part::Input,
     };
+    let classes = use_part_classes(part, classes, classes_valid, classes_invalid)
+        .with_state(binding.dirty, binding.valid);
+    let mask = mask.clone().or_else(|| form.meta().mask().cloned());
+    let node_ref = use_node_ref();
+    let caret = use_mut_ref(|| None::<u32>);
 
-    // If a valid bulma/ybc input class return early with ybc element
-    #[cfg(feature = "ybc")]
-    if let Some(input_type) = input_type.ybc_type() {
-        return html! {
-            <ybc::Input
-                name="input"
-                {classes}
-                r#type={input_type}
-                autocomplete={*autocomplete}
-                {placeholder}
-                value={form.state().value().to_owned()}
-                update={oninput}
-                disabled={*disabled}
-            />
-        };
+    {
+        let node_ref = node_ref.clone();
+        let caret = caret.clone();
+        use_effect(move || {
+            if let (Some(position), Some(input)) = (
+                caret.borrow_mut().take(),
+                node_ref.cast::<HtmlInputElement>(),
+            ) {
+                let _ = input.set_selection_range(position, position);
+            }
+        });
     }
 
-    // If not one of the valid bulma/ybc input classes, still add the 'input' class so it's styled properly.
+    let (value, oninput) = match &mask {
+        Some(mask) => (
+            AttrValue::from(mask.format(&binding.value)),
+            chain(
+                &masked_oninput(mask.clone(), binding.onset.clone(), caret),
+                oninput,
+            ),
+        ),
+        None => (binding.value.clone(), chain(&binding.oninput, oninput)),
+    };
+
     #[cfg(feature = "ybc")]
-    let classes = classes!(classes, "input");
+    let classes = classes!(classes, input_type.is_bulma_input().then_some("input"));
 
     let autocomplete = if *autocomplete { "on" } else { "off" };
 
     html! {
         <input
-            // id={form.field_name()}
+            ref={node_ref}
+            id={binding.id}
+            name={binding.name}
             class={classes}
-            type={*input_type}
+            type={input_type}
             {autocomplete}
             {placeholder}
-            value={form.state().value().to_owned()}
+            {value}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
             {oninput}
+            onblur={binding.onblur}
             disabled={*disabled}
         />
     }
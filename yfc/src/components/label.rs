@@ -0,0 +1,52 @@
+use yew::prelude::*;
+
+use crate::{
+    form::Form,
+    form_state::StateProvider,
+    theme::{use_part_classes, Part},
+};
+
+#[derive(Properties, Clone)]
+pub struct LabelProps<T: StateProvider> {
+    pub form: Form<T>,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Rendered instead of the label of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl_props_eq!([T: StateProvider] LabelProps<T> {
+    form,
+    classes,
+    children,
+});
+
+#[function_component(Label)]
+pub fn label<T: StateProvider + 'static>(
+    LabelProps {
+        form,
+        classes,
+        children,
+    }: &LabelProps<T>,
+) -> Html {
+    let meta = form.meta();
+    let empty = Classes::new();
+    let classes = use_part_classes(Part::Label, classes, &empty, &empty).classes;
+
+    // Groups of controls can't be labelled with `for`, they refer to the id instead
+    let id = meta.id().map(|id| AttrValue::from(format!("{id}-label")));
+
+    #[cfg(feature = "ybc")]
+    let classes = classes!(classes, "label");
+
+    html! {
+        <label {id} class={classes.clone()} for={meta.id()}>
+            if children.is_empty() {
+                {meta.label().cloned().unwrap_or_default()}
+            } else {
+                {children.clone()}
+            }
+        </label>
+    }
+}
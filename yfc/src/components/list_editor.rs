@@ -0,0 +1,323 @@
+use yew::prelude::*;
+
+use crate::{form::Form, form_state::StateProvider, model::ModelDefault};
+
+/// The row of a [`ListEditor`] to render.
+pub struct ListRow<T: StateProvider> {
+    pub form: Form<T>,
+    pub index: usize,
+}
+
+/// Stable keys of the rows, moved along with their elements so the rows keep their components.
+///
+/// Rows added or removed outside of the editor get keys at or are dropped from the end.
+#[derive(Default)]
+struct RowKeys {
+    keys: Vec<usize>,
+    next: usize,
+}
+
+impl RowKeys {
+    fn sync(&mut self, len: usize) {
+        while self.keys.len() < len {
+            self.push();
+        }
+        self.keys.truncate(len);
+    }
+
+    fn push(&mut self) {
+        self.keys.push(self.next);
+        self.next += 1;
+    }
+
+    fn index(&self, key: usize) -> Option<usize> {
+        self.keys.iter().position(|k| *k == key)
+    }
+
+    /** Adds the key of a new row at the end of a list of `len`, returns whether `max` allows it. */
+    fn add(&mut self, len: usize, max: Option<usize>) -> bool {
+        if !can_add(len, max) {
+            return false;
+        }
+
+        self.sync(len);
+        self.push();
+        true
+    }
+
+    /** Removes the key of a row from a list of `len`, returns its index unless `min` prevents it. */
+    fn remove(&mut self, key: usize, len: usize, min: usize) -> Option<usize> {
+        let index = self.index(key).filter(|_| len > min)?;
+        self.keys.remove(index);
+        Some(index)
+    }
+
+    /** Moves the key of a row up or down, returns the indices to swap unless it's at the edge. */
+    fn shift(&mut self, key: usize, up: bool) -> Option<(usize, usize)> {
+        let index = self.index(key)?;
+        let target = match up {
+            true => index.checked_sub(1),
+            false => Some(index + 1).filter(|target| *target < self.keys.len()),
+        }?;
+
+        self.keys.swap(index, target);
+        Some((index, target))
+    }
+}
+
+/** Returns whether a row can be added to a list of `len` without going beyond `max`. */
+fn can_add(len: usize, max: Option<usize>) -> bool {
+    match max {
+        Some(max) => len < max,
+        None => true,
+    }
+}
+
+#[derive(Properties, Clone)]
+pub struct ListEditorProps<T: StateProvider> {
+    pub form: Form<Vec<T>>,
+    /// Renders the content of a row, the editor adds the buttons to move and remove it
+    pub row: Callback<ListRow<T>, Html>,
+    /// Rows can't be removed below this, missing rows are added once rendered
+    #[prop_or_default]
+    pub min: usize,
+    /// Rows can't be added beyond this
+    #[prop_or_default]
+    pub max: Option<usize>,
+    /// Whether to render the buttons moving rows up and down
+    #[prop_or(true)]
+    pub reorder: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub classes_row: Classes,
+    #[prop_or_default]
+    pub classes_button: Classes,
+    #[prop_or_else(|| "Add".into())]
+    pub label_add: AttrValue,
+    #[prop_or_else(|| "Remove".into())]
+    pub label_remove: AttrValue,
+    #[prop_or_else(|| "Move up".into())]
+    pub label_up: AttrValue,
+    #[prop_or_else(|| "Move down".into())]
+    pub label_down: AttrValue,
+}
+
+impl_props_eq!([T: StateProvider] ListEditorProps<T> {
+    form,
+    row,
+    min,
+    max,
+    reorder,
+    disabled,
+    classes,
+    classes_row,
+    classes_button,
+    label_add,
+    label_remove,
+    label_up,
+    label_down,
+});
+
+/// Edits a list with a row per element, with buttons to add, remove and reorder the rows.
+///
+/// The buttons look up the current index of their row when clicked, so they act on the right
+/// element even when the list changed since rendering. Rows are keyed by element instead of
+/// index, so the state of their components moves along when rows are removed or reordered.
+///
+/// New rows are created with [`ModelDefault`], unlike [`Form::reset`] which only needs
+/// [`ModelReset`](crate::model::ModelReset). A row model that skips a field without a `Default`
+/// therefore needs a `#[yfc(default = ...)]` on that field to be listed here.
+#[function_component(ListEditor)]
+pub fn list_editor<T>(
+    ListEditorProps {
+        form,
+        row,
+        min,
+        max,
+        reorder,
+        disabled,
+        classes,
+        classes_row,
+        classes_button,
+        label_add,
+        label_remove,
+        label_up,
+        label_down,
+    }: &ListEditorProps<T>,
+) -> Html
+where
+    T: StateProvider + ModelDefault + 'static,
+{
+    let keys = use_mut_ref(RowKeys::default);
+    let len = form.len();
+    keys.borrow_mut().sync(len);
+
+    {
+        let form = form.clone();
+        let min = *min;
+
+        use_effect(move || {
+            if form.len() < min {
+                let mut list = form.state_mut();
+                while list.len() < min {
+                    list.push_default();
+                }
+            }
+        });
+    }
+
+    let onadd = {
+        let form = form.clone();
+        let keys = keys.clone();
+        let max = *max;
+
+        Callback::from(move |_: MouseEvent| {
+            if keys.borrow_mut().add(form.len(), max) {
+                form.state_mut().push_default();
+            }
+        })
+    };
+
+    let onremove = |key: usize| {
+        let form = form.clone();
+        let keys = keys.clone();
+        let min = *min;
+
+        Callback::from(move |_: MouseEvent| {
+            let index = keys.borrow_mut().remove(key, form.len(), min);
+
+            if let Some(index) = index {
+                form.state_mut().remove(index);
+            }
+        })
+    };
+
+    let onmove = |key: usize, up: bool| {
+        let form = form.clone();
+        let keys = keys.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let swap = keys.borrow_mut().shift(key, up);
+
+            if let Some((index, target)) = swap {
+                form.state_mut().swap(index, target);
+            }
+        })
+    };
+
+    let row_keys = keys.borrow().keys.clone();
+    let can_add = can_add(len, *max);
+    let can_remove = len > *min;
+
+    html! {
+        <div class={classes.clone()}>
+            {for row_keys.into_iter().enumerate().map(|(index, key)| html! {
+                <div {key} class={classes_row.clone()}>
+                    {row.emit(ListRow { form: form.elem_form(index), index })}
+                    if *reorder {
+                        <button
+                            type="button"
+                            class={classes_button.clone()}
+                            disabled={*disabled || index == 0}
+                            onclick={onmove(key, true)}
+                        >
+                            {label_up}
+                        </button>
+                        <button
+                            type="button"
+                            class={classes_button.clone()}
+                            disabled={*disabled || index + 1 == len}
+                            onclick={onmove(key, false)}
+                        >
+                            {label_down}
+                        </button>
+                    }
+                    <button
+                        type="button"
+                        class={classes_button.clone()}
+                        disabled={*disabled || !can_remove}
+                        onclick={onremove(key)}
+                    >
+                        {label_remove}
+                    </button>
+                </div>
+            })}
+            <button
+                type="button"
+                class={classes_button.clone()}
+                disabled={*disabled || !can_add}
+                onclick={onadd}
+            >
+                {label_add}
+            </button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(len: usize) -> RowKeys {
+        let mut keys = RowKeys::default();
+        keys.sync(len);
+        keys
+    }
+
+    #[test]
+    fn test_add() {
+        let mut row_keys = keys(2);
+
+        assert!(row_keys.add(2, Some(3)));
+        assert_eq!(row_keys.keys, [0, 1, 2]);
+        assert!(!row_keys.add(3, Some(3)));
+        assert_eq!(row_keys.keys, [0, 1, 2]);
+        assert!(row_keys.add(3, None));
+        assert_eq!(row_keys.keys, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut row_keys = keys(3);
+
+        assert_eq!(row_keys.remove(1, 3, 1), Some(1));
+        assert_eq!(row_keys.keys, [0, 2]);
+        assert_eq!(row_keys.remove(1, 2, 1), None);
+        assert_eq!(row_keys.remove(2, 2, 1), Some(1));
+        assert_eq!(row_keys.remove(0, 1, 1), None);
+        assert_eq!(row_keys.keys, [0]);
+
+        // Removed keys aren't handed out again, so new rows don't take over old components
+        assert!(row_keys.add(1, None));
+        assert_eq!(row_keys.keys, [0, 3]);
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut row_keys = keys(3);
+
+        assert_eq!(row_keys.shift(0, true), None);
+        assert_eq!(row_keys.shift(2, false), None);
+        assert_eq!(row_keys.shift(0, false), Some((0, 1)));
+        assert_eq!(row_keys.keys, [1, 0, 2]);
+        assert_eq!(row_keys.shift(2, true), Some((2, 1)));
+        assert_eq!(row_keys.keys, [1, 2, 0]);
+        assert_eq!(row_keys.shift(5, true), None);
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut row_keys = keys(2);
+
+        // Rows added or removed outside of the editor are synced at the end
+        row_keys.sync(4);
+        assert_eq!(row_keys.keys, [0, 1, 2, 3]);
+        row_keys.sync(1);
+        assert_eq!(row_keys.keys, [0]);
+        assert!(row_keys.add(3, None));
+        assert_eq!(row_keys.keys, [0, 4, 5, 6]);
+    }
+}
@@ -1,11 +1,44 @@
+/// Implements `PartialEq` for the props of a component generic over a model by comparing the
+/// listed fields, as deriving it would require the model to be `PartialEq`, which models don't
+/// need to be.
+macro_rules! impl_props_eq {
+    ([$($generics:tt)*] $props:ty { $($field:ident),+ $(,)? }) => {
+        impl<$($generics)*> PartialEq for $props {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+    };
+}
+
 pub mod checkbox;
+pub mod checkbox_group;
+pub mod combobox;
+pub mod error_summary;
 pub mod file;
+pub mod form_field;
 pub mod input;
+pub mod label;
+pub mod list_editor;
+pub mod multi_select;
+pub mod number;
+pub mod provider;
+pub mod radio;
 pub mod select;
 pub mod textarea;
 
-pub use checkbox::Checkbox;
+pub use checkbox::{Checkbox, TriStateCheckbox, ValueCheckbox};
+pub use checkbox_group::{CheckboxGroup, CheckboxOption};
+pub use combobox::{Combobox, SuggestionProvider};
+pub use error_summary::ErrorSummary;
 pub use file::File;
-pub use input::{Input, InputType};
+pub use form_field::FormField;
+pub use input::{Input, InputType, InputValue};
+pub use label::Label;
+pub use list_editor::{ListEditor, ListRow};
+pub use multi_select::MultiSelect;
+pub use number::{NumberError, NumberInput, RangeInput};
+pub use provider::{FormProvider, FormScope, ThemeProvider};
+pub use radio::{Radio, RadioGroup};
 pub use select::{Select, SelectOption};
-pub use textarea::TextArea;
+pub use textarea::{LengthError, TextArea};
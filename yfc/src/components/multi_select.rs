@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use web_sys::{wasm_bindgen::JsCast, HtmlOptionElement, HtmlSelectElement};
+use yew::{html::ChildrenRenderer, prelude::*};
+
+use crate::{
+    components::{form_field::touch_all_callback, select::Options},
+    form::Form,
+    hooks::use_described_by,
+    multi_value::MultiValue,
+    theme::{use_part_classes, Part},
+    visit::Visit,
+};
+
+#[derive(Properties, Clone)]
+pub struct MultiSelectProps<T: MultiValue> {
+    pub form: Form<T>,
+    pub children: ChildrenRenderer<Options>,
+    #[prop_or_default]
+    pub autocomplete: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// The number of visible options
+    #[prop_or_default]
+    pub size: Option<u32>,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub classes_valid: Classes,
+    #[prop_or_default]
+    pub classes_invalid: Classes,
+    #[prop_or_default]
+    pub onchange: Callback<Event>,
+}
+
+impl_props_eq!([T: MultiValue] MultiSelectProps<T> {
+    form,
+    children,
+    autocomplete,
+    disabled,
+    size,
+    classes,
+    classes_valid,
+    classes_invalid,
+    onchange,
+});
+
+/// A `<select multiple>` bound to a list or set, an option is selected when its value is in the model.
+#[function_component(MultiSelect)]
+pub fn multi_select<T>(
+    MultiSelectProps {
+        form,
+        children,
+        autocomplete,
+        disabled,
+        size,
+        classes,
+        classes_valid,
+        classes_invalid,
+        onchange,
+    }: &MultiSelectProps<T>,
+) -> Html
+where
+    T: MultiValue + 'static,
+    T::State: Visit,
+{
+    let meta = form.meta();
+    let described_by = use_described_by();
+    let onblur = touch_all_callback(form);
+    let (dirty, valid) = {
+        let state = form.state();
+        (T::dirty(&state), T::valid(&state))
+    };
+    let classes = use_part_classes(Part::Select, classes, classes_valid, classes_invalid)
+        .with_state(dirty, valid);
+
+    let onchange = {
+        let form = form.clone();
+
+        onchange.reform(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let options = select.selected_options();
+                let values = (0..options.length())
+                    .filter_map(|index| options.item(index))
+                    .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+                    .map(|option| option.value())
+                    .collect::<Vec<_>>();
+
+                form.set_values(&values);
+            }
+
+            e
+        })
+    };
+
+    let model = form.model();
+
+    html! {
+        <select
+            id={meta.id()}
+            name={meta.name()}
+            multiple=true
+            size={size.map(|size| size.to_string())}
+            autocomplete={if *autocomplete {"on"} else {"off"}}
+            disabled={*disabled}
+            class={classes}
+            aria-invalid={(!valid).then_some("true")}
+            aria-required={meta.required().then_some("true")}
+            aria-describedby={described_by}
+            {onchange}
+            {onblur}
+        >
+            { for children.iter().map(|option| {
+                match option {
+                    Options::Controlled(mut option) => {
+                        let props = Rc::make_mut(&mut option.props);
+                        props.selected = model.contains_value(&props.value);
+                        option.into()
+                    },
+                    Options::Uncontrolled(option) => {
+                        option
+                    }
+                }
+            })}
+        </select>
+    }
+}
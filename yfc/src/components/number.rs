@@ -0,0 +1,215 @@
+use std::{fmt::Display, rc::Rc};
+
+use web_sys::{HtmlInputElement, InputEvent};
+use yew::prelude::*;
+
+use crate::{
+    bindings::{apply_check, use_input_binding},
+    components::InputType,
+    form::Form,
+    form_state::StateMut,
+    form_value::Numeric,
+    theme::{use_part_classes, Part},
+};
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct NumberInputProps<T: Numeric> {
+    pub form: Form<T>,
+    #[prop_or_default]
+    pub min: Option<T>,
+    #[prop_or_default]
+    pub max: Option<T>,
+    #[prop_or_default]
+    pub step: Option<T>,
+    /// Clamps out of range values into `min..=max` once the input is changed, e.g. on blur,
+    /// instead of marking the field invalid. While typing they are only marked invalid, so
+    /// typing `15` with a `min` of `10` isn't clamped at the `1`.
+    #[prop_or_default]
+    pub clamp: bool,
+    /// Overrides the placeholder of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Classes that are applied when the field is dirty and invalid
+    #[prop_or_default]
+    pub classes_invalid: Classes,
+    /// Classes that are applied when the field is dirty and valid
+    #[prop_or_default]
+    pub classes_valid: Classes,
+    #[prop_or_default]
+    pub oninput: Callback<InputEvent>,
+    /// Describes a [`NumberError`] for the validator message, instead of its English display
+    #[prop_or_default]
+    pub number_message: Option<Callback<NumberError<T>, String>>,
+}
+
+/// Why the number of a [`NumberInput`] or [`RangeInput`] is rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberError<T> {
+    NotANumber,
+    TooSmall { min: T },
+    TooLarge { max: T },
+}
+
+impl<T: Numeric> Display for NumberError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberError::NotANumber => write!(f, "Must be a number"),
+            NumberError::TooSmall { min } => write!(f, "Must be at least {}", min.value()),
+            NumberError::TooLarge { max } => write!(f, "Must be at most {}", max.value()),
+        }
+    }
+}
+
+/// Reads the number of an input, along with why it's out of range.
+///
+/// Fails when there's no number to write, e.g. for partial input like `-` or `1e`, which is then
+/// left as typed instead of being replaced by the last number.
+fn read_number<T: Numeric>(
+    number: f64,
+    empty: bool,
+    min: Option<T>,
+    max: Option<T>,
+    clamp: bool,
+) -> Result<(T, Option<NumberError<T>>), NumberError<T>> {
+    if let Some(value) = T::empty().filter(|_| empty) {
+        return Ok((value, None));
+    }
+
+    let mut value = T::from_f64(number).ok_or(NumberError::NotANumber)?;
+
+    if clamp {
+        value = match (min, max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        };
+    }
+
+    let error = match (min, max) {
+        (Some(min), _) if value < min => Some(NumberError::TooSmall { min }),
+        (_, Some(max)) if value > max => Some(NumberError::TooLarge { max }),
+        _ => None,
+    };
+
+    Ok((value, error))
+}
+
+#[function_component(NumberInput)]
+pub fn number_input<T: Numeric + 'static>(props: &NumberInputProps<T>) -> Html {
+    use_numeric_input(props, InputType::Number)
+}
+
+/// A slider, the browser keeps its value within `min..=max` which default to `0` and `100`.
+#[function_component(RangeInput)]
+pub fn range_input<T: Numeric + 'static>(props: &NumberInputProps<T>) -> Html {
+    use_numeric_input(props, InputType::Range)
+}
+
+#[hook]
+fn use_numeric_input<T>(props: &NumberInputProps<T>, input_type: InputType) -> Html
+where
+    T: Numeric + 'static,
+{
+    let NumberInputProps {
+        form,
+        min,
+        max,
+        step,
+        clamp,
+        placeholder,
+        disabled,
+        classes,
+        classes_invalid,
+        classes_valid,
+        oninput,
+        number_message,
+    } = props;
+    // The input events are handled here, as numbers are read through `valueAsNumber`
+    let binding = use_input_binding(form);
+    let placeholder = placeholder.clone().or_else(|| binding.placeholder.clone());
+    let part = match input_type {
+        InputType::Range => Part::Range,
+        _ => Part::Input,
+    };
+    let classes = use_part_classes(part, classes, classes_valid, classes_invalid)
+        .with_state(binding.dirty, binding.valid);
+
+    // Bulma has no class for sliders, but number inputs are styled like any other input
+    #[cfg(feature = "ybc")]
+    let classes = classes!(classes, input_type.is_bulma_input().then_some("input"));
+
+    let failed = use_mut_ref(|| false);
+
+    // Writes the number of the input, clamped only once it's changed so typing isn't interrupted
+    let update = {
+        let form = form.clone();
+        let (min, max) = (*min, *max);
+        let number_message = number_message.clone();
+
+        Rc::new(move |input: &HtmlInputElement, clamp: bool| {
+            // Partial input has no value either, but the browser flags it as bad
+            let empty = input.value().is_empty() && !input.validity().bad_input();
+
+            let error = match read_number(input.value_as_number(), empty, min, max, clamp) {
+                Ok((value, error)) => {
+                    let (mut model, mut field) = form.state_mut().split();
+                    field.set_value(value.value());
+                    *model = value;
+                    error
+                }
+                Err(error) => Some(error),
+            };
+            let message = error.map(|error| match &number_message {
+                Some(number_message) => number_message.emit(error),
+                None => error.to_string(),
+            });
+
+            apply_check(&form, &mut failed.borrow_mut(), message);
+        })
+    };
+
+    let oninput = {
+        let update = update.clone();
+
+        oninput.reform(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                update(&input, false);
+            }
+            e
+        })
+    };
+    let onchange = {
+        let clamp = *clamp;
+
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>().filter(|_| clamp) {
+                update(&input, true);
+            }
+        })
+    };
+
+    html! {
+        <input
+            id={binding.id}
+            name={binding.name}
+            class={classes}
+            type={input_type}
+            min={min.map(|min| min.value())}
+            max={max.map(|max| max.value())}
+            step={step.map(|step| step.value())}
+            {placeholder}
+            value={binding.value}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
+            {oninput}
+            {onchange}
+            onblur={binding.onblur}
+            disabled={*disabled}
+        />
+    }
+}
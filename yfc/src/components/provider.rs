@@ -0,0 +1,86 @@
+use yew::prelude::*;
+
+use crate::{
+    form::Form,
+    form_state::StateProvider,
+    hooks::use_form_context,
+    model::{ModelRelation, TypedRelation},
+    theme::ThemeContext,
+};
+
+#[derive(Properties, Clone)]
+pub struct FormProviderProps<T: StateProvider> {
+    pub form: Form<T>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl_props_eq!([T: StateProvider] FormProviderProps<T> {
+    form,
+    children,
+});
+
+/// Provides the form to its children, which get it with [`use_form_context`].
+#[function_component(FormProvider)]
+pub fn form_provider<T: StateProvider + 'static>(
+    FormProviderProps { form, children }: &FormProviderProps<T>,
+) -> Html {
+    html! {
+        <ContextProvider<Form<T>> context={form.clone()}>
+            {children.clone()}
+        </ContextProvider<Form<T>>>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct FormScopeProps<R: TypedRelation> {
+    pub relation: R,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Seeds the form of the enclosing provider with `relation` and provides the result.
+///
+/// Panics if there is no [`FormProvider`] or [`FormScope`] for the parent of the relation.
+#[function_component(FormScope)]
+pub fn form_scope<R>(FormScopeProps { relation, children }: &FormScopeProps<R>) -> Html
+where
+    R: TypedRelation + ModelRelation<R::Parent, R::Child> + 'static,
+    R::Parent: 'static,
+    R::Child: 'static,
+{
+    let parent = use_form_context::<R::Parent>().unwrap_or_else(|| {
+        panic!(
+            "FormScope requires a form for `{}` to be provided",
+            std::any::type_name::<R::Parent>()
+        )
+    });
+    let form = use_memo(
+        |(parent, relation)| parent.seed(relation.clone()),
+        (parent, relation.clone()),
+    );
+
+    html! {
+        <ContextProvider<Form<R::Child>> context={(*form).clone()}>
+            {children.clone()}
+        </ContextProvider<Form<R::Child>>>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ThemeProviderProps {
+    /// Compared by reference, so create it once, e.g. with `use_memo`
+    pub theme: ThemeContext,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Provides a [`Theme`](crate::theme::Theme) to the components below it.
+#[function_component(ThemeProvider)]
+pub fn theme_provider(ThemeProviderProps { theme, children }: &ThemeProviderProps) -> Html {
+    html! {
+        <ContextProvider<ThemeContext> context={theme.clone()}>
+            {children.clone()}
+        </ContextProvider<ThemeContext>>
+    }
+}
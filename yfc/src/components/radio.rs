@@ -0,0 +1,187 @@
+use std::rc::Rc;
+
+use yew::{html::ChildrenRenderer, prelude::*, virtual_dom::VChild};
+
+use crate::{
+    bindings::{chain, use_input_binding},
+    form::Form,
+    form_value::FormValue,
+    theme::{use_part_classes, Part},
+};
+
+#[derive(Clone, PartialEq)]
+pub enum Radios {
+    Controlled(VChild<Radio>),
+    Uncontrolled(Html),
+}
+
+impl From<VChild<Radio>> for Radios {
+    fn from(child: VChild<Radio>) -> Self {
+        Radios::Controlled(child)
+    }
+}
+
+impl From<Html> for Radios {
+    fn from(child: Html) -> Self {
+        Radios::Uncontrolled(child)
+    }
+}
+
+impl From<Radios> for Html {
+    fn from(radios: Radios) -> Self {
+        match radios {
+            Radios::Controlled(child) => child.into(),
+            Radios::Uncontrolled(child) => child,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct RadioGroupProps<T: FormValue> {
+    pub form: Form<T>,
+    pub children: ChildrenRenderer<Radios>,
+    /// Overrides the name the radios share, required for a form without a name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub classes_valid: Classes,
+    #[prop_or_default]
+    pub classes_invalid: Classes,
+    #[prop_or_default]
+    pub onchange: Callback<AttrValue>,
+}
+
+/// A group of [`Radio`]s, one of which is checked when its value matches the value of the form.
+///
+/// The radios share the name of the field, so the browser moves between them with the arrow keys
+/// and only the checked one is tabbable. The group is labelled by the [`Label`](crate::Label) of
+/// the field, e.g. the one of a [`FormField`](crate::FormField).
+///
+/// # Panics
+///
+/// When the form has no name and no `name` is given.
+#[function_component(RadioGroup)]
+pub fn radio_group<T: FormValue + 'static>(
+    RadioGroupProps {
+        form,
+        children,
+        name,
+        disabled,
+        classes,
+        classes_valid,
+        classes_invalid,
+        onchange,
+    }: &RadioGroupProps<T>,
+) -> Html {
+    let binding = use_input_binding(form);
+    let name = name
+        .clone()
+        .or_else(|| binding.name.clone())
+        .expect("a `RadioGroup` needs a `name` for a form without one");
+    let labelled_by = binding
+        .id
+        .as_ref()
+        .map(|id| AttrValue::from(format!("{id}-label")));
+    let classes = use_part_classes(Part::Group, classes, classes_valid, classes_invalid)
+        .with_state(binding.dirty, binding.valid);
+    let onselect = chain(&binding.onset, onchange);
+
+    html! {
+        <div
+            class={classes}
+            role="radiogroup"
+            aria-labelledby={labelled_by}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
+        >
+            { for children.iter().map(|radio| {
+                match radio {
+                    Radios::Controlled(mut radio) => {
+                        let props = Rc::make_mut(&mut radio.props);
+                        props.checked = props.value == binding.value;
+                        props.name = name.clone();
+                        props.disabled |= *disabled;
+                        props.onselect = onselect.clone();
+                        props.onblur = binding.onblur.clone();
+                        radio.into()
+                    },
+                    Radios::Uncontrolled(radio) => {
+                        radio
+                    }
+                }
+            })}
+        </div>
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct RadioProps {
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub children: Option<Children>,
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    checked: bool,
+    #[prop_or_default]
+    name: AttrValue,
+    #[prop_or_default]
+    onselect: Callback<AttrValue>,
+    #[prop_or_default]
+    onblur: Callback<FocusEvent>,
+}
+
+#[function_component(Radio)]
+pub fn radio(
+    RadioProps {
+        value,
+        children,
+        classes,
+        disabled,
+        checked,
+        name,
+        onselect,
+        onblur,
+        ..
+    }: &RadioProps,
+) -> Html {
+    let label = match children {
+        Some(children) => children.iter().collect::<Html>(),
+        None => html! {{value}},
+    };
+
+    // The classes of the props are applied to the label, the theme styles the input itself
+    let empty = Classes::new();
+    let input_classes = use_part_classes(Part::Radio, &empty, &empty, &empty).classes;
+    let onchange = {
+        let value = value.clone();
+        onselect.reform(move |_: Event| value.clone())
+    };
+
+    // Bulma styles the label, as `ybc::Radio` renders it, which has no blur prop
+    #[cfg(feature = "ybc")]
+    let classes = classes!("radio", classes.clone());
+
+    html! {
+        <label class={classes.clone()}>
+            <input
+                class={input_classes}
+                type="radio"
+                {name}
+                value={value.clone()}
+                checked={*checked}
+                disabled={*disabled}
+                {onchange}
+                onblur={onblur.clone()}
+            />
+            {label}
+        </label>
+    }
+}
@@ -1,11 +1,13 @@
 use std::rc::Rc;
 
-use web_sys::HtmlSelectElement;
-#[cfg(feature = "ybc")]
-use ybc;
 use yew::{html::ChildrenRenderer, prelude::*, virtual_dom::VChild};
 
-use crate::{form::Form, form_state::ValueStateMut, form_value::FormValue};
+use crate::{
+    bindings::{chain, use_input_binding},
+    form::Form,
+    form_value::FormValue,
+    theme::{use_part_classes, Part},
+};
 
 #[derive(Clone, PartialEq)]
 pub enum Options {
@@ -55,7 +57,7 @@ pub struct SelectProps<T: FormValue> {
 }
 
 #[function_component(Select)]
-pub fn select<T: FormValue>(
+pub fn select<T: FormValue + 'static>(
     SelectProps {
         form,
         autocomplete,
@@ -68,65 +70,35 @@ pub fn select<T: FormValue>(
         onchange,
     }: &SelectProps<T>,
 ) -> Html {
-    let selected = form.state().value().to_owned();
-    let classes = classes!(
-        classes.clone(),
-        form.state().dirty().then(|| match form.state().valid() {
-            true => classes_valid.clone(),
-            false => classes_invalid.clone(),
-        })
-    );
-
-    let onchange = {
-        let form = form.clone();
-
-        onchange.reform(move |e: Event| {
-            if let Some(input) = e.target_dyn_into::<HtmlSelectElement>() {
-                form.state_mut().set(input.value());
-            }
-
-            e
-        })
-    };
+    let binding = use_input_binding(form);
+    let selected = binding.value.clone();
+    let classes = use_part_classes(Part::Select, classes, classes_valid, classes_invalid)
+        .with_state(binding.dirty, binding.valid);
+    let onchange = chain(&binding.onchange, onchange);
 
+    // Bulma styles a wrapper of the select, rendered like `ybc::Select`, which has no id,
+    // `aria-*` or blur props
     #[cfg(feature = "ybc")]
-    html! {
-       <ybc::Select
-            // name={form_field.field_name()}
-            name="select"
-            {classes}
-            disabled={*disabled}
-            update={onchange}>
-            { for children.iter().map(|option| {
-                match option {
-                    Options::Controlled(mut option) => {
-                        let mut props = Rc::make_mut(&mut option.props);
-                        props.selected = props.value == *selected;
-                        option.into()
-                    },
-                    Options::Uncontrolled(option) => {
-                        option
-                    }
-                }
-            })}
-       </ybc::Select>
-    }
+    let (classes, wrapper_classes) = (Classes::new(), classes!("select", classes));
 
-    #[cfg(not(feature = "ybc"))]
-    html! {
+    let select = html! {
         <select
-            // id={form_field.field_name()}
-            // name={form_field.field_name()}
+            id={binding.id}
+            name={binding.name}
             autocomplete={if *autocomplete {"on"} else {"off"}}
             disabled={*disabled}
             multiple={*multiple}
             class={classes}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={binding.aria_describedby}
             {onchange}
+            onblur={binding.onblur}
         >
             { for children.iter().map(|option| {
                 match option {
                     Options::Controlled(mut option) => {
-                        let mut props = Rc::make_mut(&mut option.props);
+                        let props = Rc::make_mut(&mut option.props);
                         props.selected = *props.value == *selected;
                         option.into()
                     },
@@ -136,7 +108,14 @@ pub fn select<T: FormValue>(
                 }
             })}
         </select>
-    }
+    };
+
+    #[cfg(feature = "ybc")]
+    let select = html! {
+        <div class={wrapper_classes}>{select}</div>
+    };
+
+    select
 }
 
 #[derive(Debug, Clone, PartialEq, Properties)]
@@ -145,7 +124,7 @@ pub struct SelectOptionProps {
     #[prop_or_default]
     pub children: Option<Children>,
     #[prop_or_default]
-    selected: bool,
+    pub(crate) selected: bool,
 }
 
 #[function_component(SelectOption)]
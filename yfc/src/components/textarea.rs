@@ -1,10 +1,18 @@
-use web_sys::{HtmlTextAreaElement, InputEvent};
+use std::fmt::Display;
+
+use web_sys::InputEvent;
 use yew::{
     html::{ImplicitClone, IntoPropValue},
     prelude::*,
 };
 
-use crate::{form::Form, form_state::ValueStateMut, form_value::FormValue};
+use crate::{
+    bindings::{apply_check, chain, use_input_binding},
+    form::Form,
+    form_value::FormValue,
+    theme::{use_part_classes, Part},
+    transform::Transform,
+};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Wrap {
@@ -37,8 +45,9 @@ pub struct TextAreaProps<T: FormValue> {
     pub cols: u32,
     #[prop_or(5)]
     pub rows: u32,
+    /// Overrides the placeholder of the field's [`FieldMeta`](crate::FieldMeta)
     #[prop_or_default]
-    pub placeholder: AttrValue,
+    pub placeholder: Option<AttrValue>,
     #[prop_or_default]
     pub disabled: bool,
     #[prop_or(Wrap::Soft)]
@@ -49,10 +58,73 @@ pub struct TextAreaProps<T: FormValue> {
     pub autocomplete: bool,
     #[prop_or_default]
     pub autocorrect: bool,
+    /// The maximum number of characters, longer values make the field invalid
+    #[prop_or_default]
+    pub maxlength: Option<u32>,
+    /// The minimum number of characters, shorter values make the field invalid
+    #[prop_or_default]
+    pub minlength: Option<u32>,
+    /// Describes a [`LengthError`] for the validator message, instead of its English display
+    #[prop_or_default]
+    pub length_message: Option<Callback<LengthError, String>>,
+    /// Renders the number of characters below the textarea, out of `maxlength` when it's set
+    #[prop_or_default]
+    pub counter: bool,
+    /// Grows `rows` to the number of lines of the value, up to `max_rows`
+    #[prop_or_default]
+    pub auto_grow: bool,
+    #[prop_or_default]
+    pub max_rows: Option<u32>,
+    /// Overrides the transform of the field's [`FieldMeta`](crate::FieldMeta)
+    #[prop_or_default]
+    pub transform: Option<Transform>,
+}
+
+/// Why the value of a [`TextArea`] is out of its `minlength` and `maxlength` bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthError {
+    TooShort { min: u32 },
+    TooLong { max: u32 },
+}
+
+impl Display for LengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LengthError::TooShort { min } => write!(f, "Must be at least {min} characters"),
+            LengthError::TooLong { max } => write!(f, "Must be at most {max} characters"),
+        }
+    }
+}
+
+impl LengthError {
+    /** Checks a value of `length` characters against the `minlength` and `maxlength` bounds. */
+    pub fn check(length: usize, minlength: Option<u32>, maxlength: Option<u32>) -> Option<Self> {
+        match (minlength, maxlength) {
+            (Some(min), _) if length < min as usize => Some(LengthError::TooShort { min }),
+            (_, Some(max)) if length > max as usize => Some(LengthError::TooLong { max }),
+            _ => None,
+        }
+    }
+}
+
+/** Returns the length of `value` the way browsers count it for `maxlength`, in UTF-16 code units. */
+fn text_length(value: &str) -> usize {
+    value.encode_utf16().count()
+}
+
+/** Returns the rows needed to show every line of `value`, wrapped lines aren't counted. */
+fn grown_rows(value: &str, rows: u32, max_rows: Option<u32>) -> u32 {
+    let lines = value.split('\n').count() as u32;
+    let rows = rows.max(lines);
+
+    match max_rows {
+        Some(max_rows) => rows.min(max_rows),
+        None => rows,
+    }
 }
 
 #[function_component(TextArea)]
-pub fn text_area<T: FormValue>(
+pub fn text_area<T: FormValue + 'static>(
     TextAreaProps {
         form,
         oninput,
@@ -67,42 +139,111 @@ pub fn text_area<T: FormValue>(
         spellcheck,
         autocomplete,
         autocorrect,
-        ..
+        maxlength,
+        minlength,
+        length_message,
+        counter,
+        auto_grow,
+        max_rows,
+        transform,
     }: &TextAreaProps<T>,
 ) -> Html {
-    let classes = classes!(
-        classes.clone(),
-        form.state().dirty().then(|| match form.state().valid() {
-            true => classes_valid.clone(),
-            false => classes_invalid.clone(),
+    let form = match transform {
+        Some(transform) => form
+            .clone()
+            .map_meta(|meta| meta.with_transform(transform.clone())),
+        None => form.clone(),
+    };
+    let binding = use_input_binding(&form);
+    let placeholder = placeholder.clone().or_else(|| binding.placeholder.clone());
+    let classes = use_part_classes(Part::TextArea, classes, classes_valid, classes_invalid)
+        .with_state(binding.dirty, binding.valid);
+    let length = text_length(&binding.value);
+    let rows = match auto_grow {
+        true => grown_rows(&binding.value, *rows, *max_rows),
+        false => *rows,
+    };
+    let counter_classes = use_part_classes(
+        Part::Help,
+        &Classes::new(),
+        &Classes::new(),
+        &Classes::new(),
+    )
+    .classes;
+    let counter_id = counter
+        .then(|| {
+            binding
+                .id
+                .as_ref()
+                .map(|id| AttrValue::from(format!("{id}-counter")))
         })
-    );
+        .flatten();
+    let described_by = match (&binding.aria_describedby, &counter_id) {
+        (Some(described_by), Some(counter_id)) => {
+            Some(AttrValue::from(format!("{described_by} {counter_id}")))
+        }
+        (described_by, counter_id) => described_by.clone().or_else(|| counter_id.clone()),
+    };
+
+    let failed = use_mut_ref(|| false);
 
-    let oninput = {
+    // Checked after rendering, so values set from anywhere are checked and not only input
+    {
         let form = form.clone();
 
-        oninput.reform(move |e: InputEvent| {
-            if let Some(input) = e.target_dyn_into::<HtmlTextAreaElement>() {
-                form.state_mut().set(input.value());
-            }
-            e
-        })
-    };
+        use_effect_with_deps(
+            move |(length, minlength, maxlength, length_message)| {
+                let error = LengthError::check(*length, *minlength, *maxlength).map(|error| {
+                    match length_message {
+                        Some(length_message) => length_message.emit(error),
+                        None => error.to_string(),
+                    }
+                });
+                apply_check(&form, &mut failed.borrow_mut(), error);
+            },
+            (length, *minlength, *maxlength, length_message.clone()),
+        );
+    }
 
-    html! {
+    let oninput = chain(&binding.oninput, oninput);
+
+    let textarea = html! {
         <textarea
-            // id={form.field_name()}
-            // name={form.field_name()}
+            id={binding.id}
+            name={binding.name}
             class={classes}
+            value={binding.value}
             cols={cols.to_string()}
             rows={rows.to_string()}
             {placeholder}
+            maxlength={maxlength.map(|maxlength| maxlength.to_string())}
+            minlength={minlength.map(|minlength| minlength.to_string())}
             {wrap}
             spellcheck={spellcheck.to_string()}
             autocomplete={autocomplete.to_string()}
             autocorrect={autocorrect.to_string()}
+            aria-invalid={binding.aria_invalid}
+            aria-required={binding.aria_required}
+            aria-describedby={described_by}
             {oninput}
+            onblur={binding.onblur}
             disabled={*disabled}
         />
+    };
+
+    if !counter {
+        return textarea;
+    }
+
+    let count = match maxlength {
+        Some(maxlength) => format!("{length} / {maxlength}"),
+        None => length.to_string(),
+    };
+
+    html! {
+        <>
+            {textarea}
+            <p id={counter_id} class={counter_classes} aria-live="polite">{count}</p>
+        </>
     }
 }
@@ -0,0 +1,470 @@
+use std::{
+    cell::{Ref, RefMut},
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+    rc::Rc,
+};
+
+#[cfg(feature = "bitflags")]
+use bitflags::Flags;
+
+#[cfg(feature = "bitflags")]
+use crate::flags::FlagSet;
+use crate::{
+    field::Field,
+    form::Form,
+    form_state::{SetState, StateMut, StateProvider},
+    form_value::FormValue,
+    meta::FieldMeta,
+    path::{FieldPath, PathSegment},
+};
+
+/// Mutable access to a single value found through [`FieldLookup::lookup_mut`].
+pub trait DynFieldMut {
+    fn field(&mut self) -> &mut Field;
+
+    /** Parses the value into the model and updates the field, like [`ValueStateMut::set`](crate::form_state::ValueStateMut::set). */
+    fn set(&mut self, value: &str);
+}
+
+struct FormValueFieldMut<'a, T>
+where
+    T: FormValue,
+{
+    model: &'a mut T,
+    field: &'a mut Field,
+}
+
+impl<'a, T> DynFieldMut for FormValueFieldMut<'a, T>
+where
+    T: FormValue,
+{
+    fn field(&mut self) -> &mut Field {
+        self.field
+    }
+
+    fn set(&mut self, value: &str) {
+        if let Some(model) = T::from_value(value) {
+            *self.model = model;
+        }
+        self.field.set_value(value);
+    }
+}
+
+/// Finds the [`Field`] of a value by its [`FieldPath`], implemented by the derive for models.
+///
+/// The fields of models are found by their Rust identifier, not by their `#[yfc(name = "...")]`.
+pub trait FieldLookup: StateProvider {
+    fn lookup<'a>(state: &'a Self::State, path: &[PathSegment]) -> Option<&'a Field>;
+    /** Returns the metadata of the value at `path` given the metadata `meta` of this value, by default that of a leaf. */
+    fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+        path.is_empty().then_some(meta)
+    }
+    fn lookup_mut<'a>(
+        model: &'a mut Self,
+        state: &'a mut Self::State,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>>;
+}
+
+impl<T> FieldLookup for T
+where
+    T: FormValue,
+{
+    fn lookup<'a>(state: &'a Field, path: &[PathSegment]) -> Option<&'a Field> {
+        path.is_empty().then_some(state)
+    }
+
+    fn lookup_mut<'a>(
+        model: &'a mut Self,
+        state: &'a mut Field,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>> {
+        match path.is_empty() {
+            true => Some(Box::new(FormValueFieldMut {
+                model,
+                field: state,
+            })),
+            false => None,
+        }
+    }
+}
+
+impl<T> FieldLookup for Vec<T>
+where
+    T: FieldLookup,
+{
+    fn lookup<'a>(state: &'a Self::State, path: &[PathSegment]) -> Option<&'a Field> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => T::lookup(state.get(*index)?, rest),
+            _ => None,
+        }
+    }
+
+    /** The elements share the metadata of the list, like their forms do. */
+    fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => {
+                T::lookup_meta(rest, meta.extended(format!("[{index}]")))
+            }
+            _ => None,
+        }
+    }
+
+    fn lookup_mut<'a>(
+        model: &'a mut Self,
+        state: &'a mut Self::State,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => {
+                T::lookup_mut(model.get_mut(*index)?, state.get_mut(*index)?, rest)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T, const N: usize> FieldLookup for [T; N]
+where
+    T: FieldLookup,
+{
+    fn lookup<'a>(state: &'a Self::State, path: &[PathSegment]) -> Option<&'a Field> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => T::lookup(state.get(*index)?, rest),
+            _ => None,
+        }
+    }
+
+    /** The elements share the metadata of the list, like their forms do. */
+    fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => {
+                T::lookup_meta(rest, meta.extended(format!("[{index}]")))
+            }
+            _ => None,
+        }
+    }
+
+    fn lookup_mut<'a>(
+        model: &'a mut Self,
+        state: &'a mut Self::State,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>> {
+        match path.split_first()? {
+            (PathSegment::Index(index), rest) => {
+                T::lookup_mut(model.get_mut(*index)?, state.get_mut(*index)?, rest)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Tuple elements are named by their index, e.g. `range.0`.
+fn tuple_index(segment: &PathSegment) -> Option<usize> {
+    match segment {
+        PathSegment::Field(name) => name.parse().ok(),
+        PathSegment::Index(index) => Some(*index),
+    }
+}
+
+macro_rules! impl_tuple_field_lookup {
+    ($(($($t:ident $i:tt),+)),*) => {
+        $(
+            impl<$($t),+> FieldLookup for ($($t,)+)
+            where
+                $($t: FieldLookup,)+
+            {
+                fn lookup<'a>(state: &'a Self::State, path: &[PathSegment]) -> Option<&'a Field> {
+                    let (segment, rest) = path.split_first()?;
+                    match tuple_index(segment)? {
+                        $($i => $t::lookup(&state.$i, rest),)+
+                        _ => None,
+                    }
+                }
+
+                fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+                    let (segment, rest) = path.split_first()?;
+                    match tuple_index(segment)? {
+                        $($i => $t::lookup_meta(rest, meta.extended(stringify!($i))),)+
+                        _ => None,
+                    }
+                }
+
+                fn lookup_mut<'a>(
+                    model: &'a mut Self,
+                    state: &'a mut Self::State,
+                    path: &[PathSegment],
+                ) -> Option<Box<dyn DynFieldMut + 'a>> {
+                    let (segment, rest) = path.split_first()?;
+                    match tuple_index(segment)? {
+                        $($i => $t::lookup_mut(&mut model.$i, &mut state.$i, rest),)+
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_field_lookup!((A 0, B 1), (A 0, B 1, C 2), (A 0, B 1, C 2, D 3));
+
+struct SetFieldMut<'a, S> {
+    model: &'a mut S,
+    state: &'a mut SetState,
+    value: String,
+}
+
+macro_rules! impl_set_field_lookup {
+    ($($set:ident: $($bound:path),+);*) => {
+        $(
+            impl<'a, T> DynFieldMut for SetFieldMut<'a, $set<T>>
+            where
+                T: FormValue $(+ $bound)+,
+            {
+                fn field(&mut self) -> &mut Field {
+                    self.state.get_mut(&self.value).expect("the value to be in the set")
+                }
+
+                /** Replaces the value in the set. */
+                fn set(&mut self, value: &str) {
+                    if let Some(old) = T::from_value(&self.value) {
+                        self.model.remove(&old);
+                    }
+                    if let Some(new) = T::from_value(value) {
+                        self.model.insert(new);
+                    }
+                    self.state.rename(&self.value, value);
+                    self.value = value.to_owned();
+                }
+            }
+
+            /// The values of a set are found by their string value, e.g. `tags.rust`.
+            impl<T> FieldLookup for $set<T>
+            where
+                T: FormValue $(+ $bound)+,
+            {
+                fn lookup<'a>(state: &'a SetState, path: &[PathSegment]) -> Option<&'a Field> {
+                    match path {
+                        [PathSegment::Field(value)] => state.get(value),
+                        _ => None,
+                    }
+                }
+
+                fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+                    match path {
+                        [PathSegment::Field(value)] => Some(meta.extended(value.clone())),
+                        _ => None,
+                    }
+                }
+
+                fn lookup_mut<'a>(
+                    model: &'a mut Self,
+                    state: &'a mut SetState,
+                    path: &[PathSegment],
+                ) -> Option<Box<dyn DynFieldMut + 'a>> {
+                    match path {
+                        [PathSegment::Field(value)] if state.get(value).is_some() => {
+                            Some(Box::new(SetFieldMut {
+                                model,
+                                state,
+                                value: value.to_string(),
+                            }))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_set_field_lookup!(HashSet: Eq, Hash; BTreeSet: Ord);
+
+#[cfg(feature = "bitflags")]
+impl<'a, F> DynFieldMut for SetFieldMut<'a, FlagSet<F>>
+where
+    F: Flags,
+{
+    fn field(&mut self) -> &mut Field {
+        self.state.get_mut(&self.value).expect("the flag to be set")
+    }
+
+    /** Replaces the flag with the one named `value`, unknown names are ignored. */
+    fn set(&mut self, value: &str) {
+        let (Some(old), Some(new)) = (F::from_name(&self.value), F::from_name(value)) else {
+            return;
+        };
+
+        self.model.0.remove(old);
+        self.model.0.insert(new);
+        self.state.rename(&self.value, value);
+        self.value = value.to_owned();
+    }
+}
+
+/// The flags are found by their name, e.g. `permissions.WRITE`.
+#[cfg(feature = "bitflags")]
+impl<F> FieldLookup for FlagSet<F>
+where
+    F: Flags,
+{
+    fn lookup<'a>(state: &'a SetState, path: &[PathSegment]) -> Option<&'a Field> {
+        match path {
+            [PathSegment::Field(value)] => state.get(value),
+            _ => None,
+        }
+    }
+
+    fn lookup_meta(path: &[PathSegment], meta: FieldMeta) -> Option<FieldMeta> {
+        match path {
+            [PathSegment::Field(value)] => Some(meta.extended(value.clone())),
+            _ => None,
+        }
+    }
+
+    fn lookup_mut<'a>(
+        model: &'a mut Self,
+        state: &'a mut SetState,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>> {
+        match path {
+            [PathSegment::Field(value)] if state.get(value).is_some() => {
+                Some(Box::new(SetFieldMut {
+                    model,
+                    state,
+                    value: value.to_string(),
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+trait ErasedForm {
+    fn meta(&self, path: &FieldPath) -> FieldMeta;
+    fn field(&self, path: &FieldPath) -> Option<Ref<'_, Field>>;
+    fn update(&self, path: &FieldPath, f: &mut dyn FnMut(&mut dyn DynFieldMut));
+}
+
+impl<T> ErasedForm for Form<T>
+where
+    T: FieldLookup,
+{
+    fn meta(&self, path: &FieldPath) -> FieldMeta {
+        T::lookup_meta(path.segments(), Form::meta(self).clone())
+            .unwrap_or_else(|| FieldMeta::new(path.to_string()).nested_in(Form::meta(self)))
+    }
+
+    fn field(&self, path: &FieldPath) -> Option<Ref<'_, Field>> {
+        Ref::filter_map(self.state(), |s| T::lookup(s, path.segments())).ok()
+    }
+
+    fn update(&self, path: &FieldPath, f: &mut dyn FnMut(&mut dyn DynFieldMut)) {
+        let (mut model, mut state): (RefMut<T>, RefMut<T::State>) = self.state_mut().split();
+        if let Some(mut field) = T::lookup_mut(&mut model, &mut state, path.segments()) {
+            f(field.as_mut());
+        };
+    }
+}
+
+/// A type-erased handle to a single value of a form, returned by [`Form::at`].
+///
+/// The handle keeps the path rather than the field, so it follows the value when the form
+/// changes. Reading a field that no longer exists, e.g. after removing a list element, gives the
+/// defaults of [`Field`] and writing to it does nothing.
+#[derive(Clone)]
+pub struct DynField {
+    form: Rc<dyn ErasedForm>,
+    path: FieldPath,
+}
+
+impl std::fmt::Debug for DynField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynField")
+            .field("path", &self.path)
+            .field("field", &self.field())
+            .finish()
+    }
+}
+
+impl DynField {
+    pub(crate) fn new<T>(form: Form<T>, path: FieldPath) -> Option<Self>
+    where
+        T: FieldLookup + 'static,
+    {
+        T::lookup(&form.state(), path.segments())?;
+
+        Some(Self {
+            form: Rc::new(form),
+            path,
+        })
+    }
+
+    /** Returns the path of the field relative to the form it was looked up in. */
+    pub fn path(&self) -> &FieldPath {
+        &self.path
+    }
+
+    /** Returns the metadata the derive generated for the field, named from the root form like the form of the field would be. */
+    pub fn meta(&self) -> FieldMeta {
+        self.form.meta(&self.path)
+    }
+
+    pub fn field(&self) -> Option<Ref<'_, Field>> {
+        self.form.field(&self.path)
+    }
+
+    pub fn value(&self) -> String {
+        self.field()
+            .map(|f| f.value().to_owned())
+            .unwrap_or_default()
+    }
+
+    pub fn dirty(&self) -> bool {
+        self.field().is_some_and(|f| f.dirty())
+    }
+
+    pub fn valid(&self) -> bool {
+        // Like the default of `Field` when the field no longer exists
+        match self.field() {
+            Some(field) => field.valid(),
+            None => true,
+        }
+    }
+
+    #[cfg(feature = "validator")]
+    pub fn message(&self) -> String {
+        self.field()
+            .map(|f| f.message().to_owned())
+            .unwrap_or_default()
+    }
+
+    /** Parses and sets the value of the field after applying its transform, re-rendering the form. */
+    pub fn set(&self, value: &str) {
+        let value = match self.meta().input_transform() {
+            Some(transform) => transform.apply(value),
+            None => value.to_owned(),
+        };
+
+        self.form.update(&self.path, &mut |field| field.set(&value));
+    }
+
+    /** Updates the [`Field`] itself, re-rendering the form. */
+    pub fn update(&self, mut f: impl FnMut(&mut Field)) {
+        self.form.update(&self.path, &mut |field| f(field.field()));
+    }
+
+    pub fn set_dirty(&self, value: bool) {
+        self.update(|field| field.set_dirty(value));
+    }
+
+    pub fn set_valid(&self, value: bool) {
+        self.update(|field| field.set_valid(value));
+    }
+
+    #[cfg(feature = "validator")]
+    pub fn set_message(&self, message: &str) {
+        self.update(|field| field.set_message(message));
+    }
+}
@@ -8,6 +8,7 @@ pub struct Field {
     value: String,
     valid: bool,
     dirty: bool,
+    touched: bool,
     #[cfg(feature = "validator")]
     message: String,
 }
@@ -28,6 +29,7 @@ impl Default for Field {
             value: "".into(),
             valid: true,
             dirty: false,
+            touched: false,
             #[cfg(feature = "validator")]
             message: "".into(),
         }
@@ -60,6 +62,10 @@ impl Field {
         self.valid
     }
 
+    pub fn set_valid(&mut self, value: bool) {
+        self.valid = value;
+    }
+
     pub fn dirty(&self) -> bool {
         self.dirty
     }
@@ -68,6 +74,15 @@ impl Field {
         self.dirty = value;
     }
 
+    /** Returns whether the user has visited the field, unlike [`Field::dirty`] it is not tied to the value. */
+    pub fn touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn set_touched(&mut self, value: bool) {
+        self.touched = value;
+    }
+
     #[cfg(feature = "validator")]
     pub fn message(&self) -> &str {
         &self.message
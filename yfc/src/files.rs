@@ -0,0 +1,332 @@
+use std::{cell::RefMut, fmt::Display, future::Future, pin::Pin, rc::Rc};
+
+use web_sys::js_sys::Uint8Array;
+
+use crate::{
+    dyn_field::{DynFieldMut, FieldLookup},
+    field::Field,
+    form_state::StateProvider,
+    form_value::FormValueState,
+    model::{impl_model_reset_by_default, ModelDefault, ModelReset},
+    path::{FieldPath, HasPath, PathSegment},
+};
+
+/// The future returned by [`FileSource::read`].
+pub type ReadFuture = Pin<Box<dyn Future<Output = Result<Vec<u8>, FileError>>>>;
+
+/// A file picked by the user, either a browser [`web_sys::File`] or a [`MemoryFile`] in tests.
+pub trait FileSource: std::fmt::Debug {
+    fn name(&self) -> &str;
+    fn size(&self) -> u64;
+    /** The MIME type reported by the browser, empty when it's unknown. */
+    fn mime_type(&self) -> &str;
+    fn read(&self) -> ReadFuture;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileError {
+    TooLarge { name: String, size: u64, max: u64 },
+    Type { name: String, mime_type: String },
+    Read(String),
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::TooLarge { name, size, max } => {
+                write!(f, "{name} is {size} bytes, at most {max} are allowed")
+            }
+            FileError::Type { name, mime_type } => {
+                write!(f, "{name} has a type that isn't accepted: {mime_type}")
+            }
+            FileError::Read(message) => write!(f, "failed to read the file: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FileError {}
+
+#[cfg(feature = "validator")]
+impl From<FileError> for validator::ValidationError {
+    fn from(error: FileError) -> Self {
+        let code = match &error {
+            FileError::TooLarge { .. } => "file_size",
+            FileError::Type { .. } => "file_type",
+            FileError::Read(_) => "file_read",
+        };
+        let mut validation_error = validator::ValidationError::new(code);
+        validation_error.message = Some(error.to_string().into());
+        validation_error
+    }
+}
+
+/// The name, size and type of a browser file are read once, as they're accessed on every render.
+#[derive(Debug)]
+struct BrowserFile {
+    file: web_sys::File,
+    name: String,
+    mime_type: String,
+}
+
+impl FileSource for BrowserFile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.file.size() as u64
+    }
+
+    fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    fn read(&self) -> ReadFuture {
+        let promise = self.file.array_buffer();
+
+        Box::pin(async move {
+            let buffer = wasm_bindgen_futures::JsFuture::from(promise)
+                .await
+                .map_err(|e| FileError::Read(format!("{e:?}")))?;
+            Ok(Uint8Array::new(&buffer).to_vec())
+        })
+    }
+}
+
+/// A file held in memory, a stand-in for browser files when testing natively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryFile {
+    pub name: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+impl MemoryFile {
+    pub fn new(name: impl Into<String>, mime_type: impl Into<String>, bytes: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            mime_type: mime_type.into(),
+            bytes,
+        }
+    }
+}
+
+impl FileSource for MemoryFile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+
+    fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    fn read(&self) -> ReadFuture {
+        let bytes = self.bytes.clone();
+        Box::pin(async move { Ok(bytes) })
+    }
+}
+
+/// A cheaply cloneable handle to a [`FileSource`], two handles are equal when they share the file.
+#[derive(Debug, Clone)]
+pub struct FileHandle(Rc<dyn FileSource>);
+
+impl FileHandle {
+    pub fn new<F: FileSource + 'static>(source: F) -> Self {
+        Self(Rc::new(source))
+    }
+
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.0.size()
+    }
+
+    pub fn mime_type(&self) -> &str {
+        self.0.mime_type()
+    }
+
+    /** Reads the contents of the file. */
+    pub async fn bytes(&self) -> Result<Vec<u8>, FileError> {
+        self.0.read().await
+    }
+
+    /// Returns whether the file matches an `accept` attribute, e.g. `image/*,.pdf`, an empty one accepts all files.
+    pub fn accepted(&self, accept: &str) -> bool {
+        let name = self.name().to_lowercase();
+        let mime_type = self.mime_type().to_lowercase();
+        let mut tokens = accept
+            .split(',')
+            .map(|token| token.trim().to_lowercase())
+            .filter(|token| !token.is_empty())
+            .peekable();
+
+        tokens.peek().is_none()
+            || tokens.any(|token| match token.strip_suffix("/*") {
+                _ if token.starts_with('.') => name.ends_with(&token),
+                Some(prefix) => mime_type.split('/').next() == Some(prefix),
+                None => mime_type == token,
+            })
+    }
+}
+
+impl PartialEq for FileHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl From<web_sys::File> for FileHandle {
+    fn from(file: web_sys::File) -> Self {
+        Self::new(BrowserFile {
+            name: file.name(),
+            mime_type: file.type_(),
+            file,
+        })
+    }
+}
+
+/// The files picked in a [`File`](crate::File) input, empty until the user picks any.
+///
+/// The state is a [`Field`] holding the names of the files.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Files(Vec<FileHandle>);
+
+impl Files {
+    pub fn new(files: Vec<FileHandle>) -> Self {
+        Self(files)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FileHandle> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn first(&self) -> Option<&FileHandle> {
+        self.0.first()
+    }
+
+    /** Checks that none of the files is larger than `max` bytes. */
+    pub fn validate_max_size(&self, max: u64) -> Result<(), FileError> {
+        match self.iter().find(|file| file.size() > max) {
+            Some(file) => Err(FileError::TooLarge {
+                name: file.name().to_owned(),
+                size: file.size(),
+                max,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /** Checks that all files match an `accept` attribute, see [`FileHandle::accepted`]. */
+    pub fn validate_accept(&self, accept: &str) -> Result<(), FileError> {
+        match self.iter().find(|file| !file.accepted(accept)) {
+            Some(file) => Err(FileError::Type {
+                name: file.name().to_owned(),
+                mime_type: file.mime_type().to_owned(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn names(&self) -> String {
+        self.iter()
+            .map(FileHandle::name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl From<Vec<FileHandle>> for Files {
+    fn from(files: Vec<FileHandle>) -> Self {
+        Self(files)
+    }
+}
+
+impl FromIterator<FileHandle> for Files {
+    fn from_iter<I: IntoIterator<Item = FileHandle>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl StateProvider for Files {
+    type State = Field;
+    type StateMut<'a> = FormValueState<'a, Files>;
+
+    fn create_state(&self) -> Self::State {
+        Field::new(self.names())
+    }
+
+    fn create_state_mut<'a>(
+        model: RefMut<'a, Self>,
+        state: RefMut<'a, Self::State>,
+    ) -> Self::StateMut<'a> {
+        FormValueState {
+            value: model,
+            field: state,
+            transform: None,
+        }
+    }
+}
+
+impl<'a> FormValueState<'a, Files> {
+    /** Replaces the files, the field holds their names. */
+    pub fn set_files(&mut self, files: Files) {
+        self.field.set_value(files.names());
+        *self.value = files;
+    }
+}
+
+impl ModelDefault for Files {
+    fn model_default() -> Self {
+        Files::default()
+    }
+}
+
+impl_model_reset_by_default!([] Files);
+
+impl HasPath for Files {
+    type Path = FieldPath;
+}
+
+struct FilesFieldMut<'a> {
+    field: &'a mut Field,
+}
+
+impl<'a> DynFieldMut for FilesFieldMut<'a> {
+    fn field(&mut self) -> &mut Field {
+        self.field
+    }
+
+    /** Files can only be picked by the user, setting them from a string is ignored. */
+    fn set(&mut self, _value: &str) {}
+}
+
+impl FieldLookup for Files {
+    fn lookup<'a>(state: &'a Field, path: &[PathSegment]) -> Option<&'a Field> {
+        path.is_empty().then_some(state)
+    }
+
+    fn lookup_mut<'a>(
+        _model: &'a mut Self,
+        state: &'a mut Field,
+        path: &[PathSegment],
+    ) -> Option<Box<dyn DynFieldMut + 'a>> {
+        match path.is_empty() {
+            true => Some(Box::new(FilesFieldMut { field: state })),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::{cell::RefMut, ops::Deref};
+
+use bitflags::Flags;
+
+use crate::{
+    field::Field,
+    form_state::{SetMut, SetState, StateProvider},
+    model::{impl_model_reset_by_default, ModelDefault, ModelReset},
+    multi_value::MultiValue,
+    path::{FieldPath, HasPath},
+};
+
+/// Binds a `bitflags` value as a set of its flag names, e.g. to a [`CheckboxGroup`](crate::CheckboxGroup).
+///
+/// Only named flags are tracked, unnamed bits are kept in the model but have no state.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlagSet<F>(pub F)
+where
+    F: Flags;
+
+impl<F> Deref for FlagSet<F>
+where
+    F: Flags,
+{
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F> From<F> for FlagSet<F>
+where
+    F: Flags,
+{
+    fn from(flags: F) -> Self {
+        Self(flags)
+    }
+}
+
+impl<F> Default for FlagSet<F>
+where
+    F: Flags,
+{
+    fn default() -> Self {
+        Self(F::empty())
+    }
+}
+
+impl<F> StateProvider for FlagSet<F>
+where
+    F: Flags,
+{
+    type State = SetState;
+    type StateMut<'a>
+        = SetMut<'a, Self>
+    where
+        F: 'a;
+
+    fn create_state(&self) -> Self::State {
+        SetState::new(self.values())
+    }
+
+    fn create_state_mut<'a>(
+        model: RefMut<'a, Self>,
+        state: RefMut<'a, Self::State>,
+    ) -> Self::StateMut<'a> {
+        SetMut { model, state }
+    }
+}
+
+impl<'a, F> SetMut<'a, FlagSet<F>>
+where
+    F: Flags + Copy,
+{
+    pub fn contains(&self, flags: F) -> bool {
+        self.model.0.contains(flags)
+    }
+
+    pub fn insert(&mut self, flags: F) {
+        for (name, _) in flags.iter_names() {
+            self.state.insert(name.to_owned());
+        }
+        self.model.0.insert(flags);
+    }
+
+    pub fn remove(&mut self, flags: F) {
+        for (name, _) in flags.iter_names() {
+            self.state.remove(name);
+        }
+        self.model.0.remove(flags);
+    }
+
+    /** Inserts the flags when they're not all contained and removes them otherwise, returns whether they're now contained. */
+    pub fn toggle(&mut self, flags: F) -> bool {
+        match self.contains(flags) {
+            true => {
+                self.remove(flags);
+                false
+            }
+            false => {
+                self.insert(flags);
+                true
+            }
+        }
+    }
+
+    pub fn select_all(&mut self) {
+        self.insert(F::all());
+    }
+
+    pub fn select_none(&mut self) {
+        self.remove(F::all());
+    }
+}
+
+impl<F> MultiValue for FlagSet<F>
+where
+    F: Flags,
+{
+    fn values(&self) -> Vec<String> {
+        self.0
+            .iter_names()
+            .map(|(name, _)| name.to_owned())
+            .collect()
+    }
+
+    fn set_values(model: &mut Self, state: &mut SetState, values: &[String]) {
+        let removed = state
+            .iter()
+            .map(|(value, _)| value.to_owned())
+            .filter(|value| !values.contains(value))
+            .collect::<Vec<_>>();
+
+        for value in removed {
+            state.remove(&value);
+        }
+
+        for value in values {
+            if F::from_name(value).is_some() {
+                state.insert(value.clone());
+            }
+        }
+
+        let unnamed = F::from_bits_retain(model.0.bits()).difference(F::all());
+        model.0 = values
+            .iter()
+            .filter_map(|value| F::from_name(value))
+            .fold(unnamed, F::union);
+    }
+
+    fn dirty(state: &SetState) -> bool {
+        state.dirty()
+    }
+
+    fn valid(state: &SetState) -> bool {
+        state.valid()
+    }
+
+    fn value_dirty(state: &SetState, value: &str) -> bool {
+        state.changed(value) || state.get(value).is_some_and(Field::dirty)
+    }
+}
+
+impl<F> ModelDefault for FlagSet<F>
+where
+    F: Flags,
+{
+    fn model_default() -> Self {
+        Self::default()
+    }
+}
+
+impl_model_reset_by_default!([F: Flags] FlagSet<F>);
+
+impl<F> HasPath for FlagSet<F>
+where
+    F: Flags,
+{
+    type Path = FieldPath;
+}
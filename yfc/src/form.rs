@@ -3,9 +3,14 @@ use std::{cell::Ref, fmt::Debug, rc::Rc};
 use yew::{html::ImplicitClone, UseStateHandle};
 
 use crate::{
-    form_state::{FormState, OwnedFormState, RefFormState, StateProvider},
+    dyn_field::{DynField, FieldLookup},
+    form_state::{FormState, OwnedFormState, RefFormState, StateMut, StateProvider, ValueStateMut},
     form_value::FormValue,
-    model::ModelRelation,
+    meta::FieldMeta,
+    model::{ElemRelation, ModelRelation, ModelReset},
+    multi_value::MultiValue,
+    path::{FieldPath, PathError},
+    visit::{FieldVisitorMut, Visit},
     Model,
 };
 
@@ -15,6 +20,7 @@ where
 {
     form_state: Rc<dyn FormState<T>>,
     counter: UseStateHandle<u32>,
+    meta: FieldMeta,
 }
 
 impl<T> Clone for Form<T>
@@ -25,6 +31,7 @@ where
         Self {
             form_state: self.form_state.clone(),
             counter: self.counter.clone(),
+            meta: self.meta.clone(),
         }
     }
 }
@@ -37,7 +44,9 @@ where
 {
     fn eq(&self, other: &Self) -> bool {
         // false
-        Rc::ptr_eq(&self.form_state, &other.form_state) && self.counter == other.counter
+        Rc::ptr_eq(&self.form_state, &other.form_state)
+            && self.counter == other.counter
+            && self.meta == other.meta
     }
 }
 
@@ -60,9 +69,27 @@ where
         self.form_state.state()
     }
 
+    pub fn model(&self) -> Ref<T> {
+        self.form_state.model()
+    }
+
+    /** Returns the state to change, its setters apply the transform of the field unless it runs on blur. */
     pub fn state_mut<'a>(&'a self) -> <T as StateProvider>::StateMut<'a> {
         self.counter.set((*self.counter).wrapping_add(1));
-        self.form_state.state_mut()
+        self.form_state
+            .state_mut()
+            .with_transform(self.meta.input_transform().cloned())
+    }
+
+    /** Returns the metadata of the field this form was seeded from. */
+    pub fn meta(&self) -> &FieldMeta {
+        &self.meta
+    }
+
+    /** Replaces the metadata, e.g. to override it with the props of a component. */
+    pub fn map_meta(mut self, f: impl FnOnce(FieldMeta) -> FieldMeta) -> Self {
+        self.meta = f(self.meta);
+        self
     }
 
     pub fn replace(&self, model: T) {
@@ -71,6 +98,92 @@ where
     }
 }
 
+impl<T> Form<T>
+where
+    T: StateProvider + ModelReset,
+{
+    /// Resets the model to its [`ModelDefault`](crate::model::ModelDefault), discarding all of
+    /// the state.
+    ///
+    /// The `#[yfc(skip)]` fields of derived models aren't form data, so they keep their values and
+    /// don't need a default.
+    pub fn reset(&self) {
+        let (mut model, mut state) = self.state_mut().split();
+        model.reset_model();
+        *state = model.create_state();
+    }
+}
+
+impl<T> Form<T>
+where
+    T: MultiValue,
+{
+    /** Replaces all the values of the model, see [`MultiValue::set_values`]. */
+    pub fn set_values(&self, values: &[String]) {
+        let (mut model, mut state) = self.state_mut().split();
+        T::set_values(&mut model, &mut state, values);
+    }
+
+    /** Adds `value` when it's missing and removes it otherwise. */
+    pub fn toggle_value(&self, value: &str) {
+        let mut values = self.model().values();
+
+        match values.iter().position(|v| v == value) {
+            Some(index) => {
+                values.remove(index);
+            }
+            None => values.push(value.to_owned()),
+        }
+
+        self.set_values(&values);
+    }
+
+    /** Adds all of `values` that are missing, e.g. the options of a [`CheckboxGroup`](crate::CheckboxGroup). */
+    pub fn select_all(&self, values: &[String]) {
+        let mut selected = self.model().values();
+        for value in values {
+            if !selected.contains(value) {
+                selected.push(value.clone());
+            }
+        }
+
+        self.set_values(&selected);
+    }
+
+    /** Removes all values. */
+    pub fn select_none(&self) {
+        self.set_values(&[]);
+    }
+}
+
+impl<T> Form<T>
+where
+    T: StateProvider,
+    T::State: Visit,
+{
+    /** Visits every field of the form mutably, re-rendering the form afterwards. */
+    pub fn visit_fields_mut<V: FieldVisitorMut>(&self, visitor: &mut V) {
+        let (_, mut state) = self.state_mut().split();
+        state.visit_mut(visitor);
+    }
+}
+
+impl<T> Form<T>
+where
+    T: FieldLookup + 'static,
+{
+    /** Looks up the value at `path`, relative to this form, returns [`None`] if there is no value there. */
+    pub fn at(&self, path: impl Into<FieldPath>) -> Option<DynField> {
+        DynField::new(self.clone(), path.into())
+    }
+
+    /** Parses the path, e.g. `items[3].price`, and looks up the value like [`Form::at`]. */
+    pub fn at_str(&self, path: &str) -> Result<DynField, PathError> {
+        let path: FieldPath = path.parse()?;
+        self.at(path.clone()).ok_or(PathError::NotFound(path))
+    }
+}
+
 impl<'a, T> Form<T>
 where
     T: Model + 'static,
@@ -79,6 +192,7 @@ where
         Self {
             form_state: Rc::new(OwnedFormState::new(model)),
             counter,
+            meta: FieldMeta::default(),
         }
     }
 
@@ -86,6 +200,7 @@ where
         Self {
             form_state: state,
             counter,
+            meta: FieldMeta::default(),
         }
     }
 
@@ -94,16 +209,34 @@ where
         C: StateProvider + 'static,
         R: ModelRelation<T, C> + 'static,
     {
+        let meta = relation.meta().nested_in(&self.meta);
+
         Form {
             form_state: Rc::new(RefFormState::<T, C, R>::new(
                 self.form_state.clone(),
                 relation,
             )),
             counter: self.counter.clone(),
+            meta,
         }
     }
-    pub fn model(&self) -> Ref<T> {
-        self.form_state.model()
+}
+
+impl<T> Form<Vec<T>>
+where
+    T: StateProvider + 'static,
+{
+    /** Returns the form of the element at `index`, which panics when used while out of bounds. */
+    pub fn elem_form(&self, index: usize) -> Form<T> {
+        self.seed(ElemRelation(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.model().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.model().is_empty()
     }
 }
 
@@ -114,4 +247,23 @@ where
     pub fn value<'a>(&'a self) -> Ref<'a, T> {
         self.form_state.model()
     }
+
+    /** Sets the value, applying the [`Transform`](crate::transform::Transform) of the field unless it runs on blur. */
+    pub fn set(&self, value: &str) {
+        self.state_mut().set(value)
+    }
+
+    /** Applies the transform of the field to the current value, done by the components on blur. */
+    pub fn apply_transform(&self) {
+        let Some(transform) = self.meta.transform() else {
+            return;
+        };
+
+        let value = self.state().value().to_owned();
+        let transformed = transform.apply(&value);
+
+        if transformed != value {
+            self.state_mut().set(transformed);
+        }
+    }
 }
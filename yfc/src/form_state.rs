@@ -1,5 +1,7 @@
 use std::{
     cell::{Ref, RefCell, RefMut},
+    collections::{BTreeMap, BTreeSet, HashSet},
+    hash::Hash,
     marker::PhantomData,
     rc::Rc,
 };
@@ -7,7 +9,8 @@ use std::{
 use crate::{
     field::Field,
     form_value::{FormValue, FormValueState},
-    model::{Model, ModelRelation},
+    model::{Model, ModelDefault, ModelRelation},
+    transform::Transform,
 };
 
 pub(crate) trait FormState<T>
@@ -112,7 +115,11 @@ where
     }
 
     fn state_mut<'a>(&'a self) -> <C as StateProvider>::StateMut<'a> {
-        StateMut::map(self.parent_state.state_mut(), &self.relation)
+        // Not `StateMut::map`, the form takes what setting depends on from its own metadata
+        let (model, state) = self.parent_state.state_mut().split();
+        let model = RefMut::map(model, |m| self.relation.relation_model_mut(m));
+        let state = RefMut::map(state, |s| self.relation.relation_state_mut(s));
+        C::create_state_mut(model, state)
     }
 
     fn replace_model(&self, new_model: C) {
@@ -148,6 +155,29 @@ where
         self.states.remove(index);
         self.values.remove(index)
     }
+
+    /** Swaps two elements along with their state, panics if either is out of bounds. */
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.states.swap(a, b);
+        self.values.swap(a, b);
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<'a, T> ListMut<'a, T>
+where
+    T: StateProvider + ModelDefault,
+{
+    pub fn push_default(&mut self) {
+        self.push(T::model_default());
+    }
 }
 
 impl<'a, T> StateMut<'a, Vec<T>> for ListMut<'a, T>
@@ -159,6 +189,43 @@ where
     }
 }
 
+pub struct ArrayMut<'a, T, const N: usize>
+where
+    T: StateProvider,
+{
+    values: RefMut<'a, [T; N]>,
+    states: RefMut<'a, [T::State; N]>,
+}
+
+impl<'a, T, const N: usize> ArrayMut<'a, T, N>
+where
+    T: StateProvider,
+{
+    /** Returns the mutable state of the element at `index`, panics if out of bounds. */
+    pub fn elem(self, index: usize) -> T::StateMut<'a> {
+        let value = RefMut::map(self.values, |v| &mut v[index]);
+        let state = RefMut::map(self.states, |s| &mut s[index]);
+        T::create_state_mut(value, state)
+    }
+}
+
+impl<'a, T, const N: usize> StateMut<'a, [T; N]> for ArrayMut<'a, T, N>
+where
+    T: StateProvider,
+{
+    fn split(self) -> (RefMut<'a, [T; N]>, RefMut<'a, [T::State; N]>) {
+        (self.values, self.states)
+    }
+}
+
+pub struct TupleMut<'a, T>
+where
+    T: StateProvider,
+{
+    model: RefMut<'a, T>,
+    state: RefMut<'a, T::State>,
+}
+
 pub trait StateProvider: Sized {
     type State: PartialEq + std::fmt::Debug;
     type StateMut<'a>: StateMut<'a, Self>
@@ -177,6 +244,15 @@ where
     T: StateProvider,
 {
     fn split(self) -> (RefMut<'a, T>, RefMut<'a, T::State>);
+
+    /** Takes the transform of the field to apply whenever the value is set. */
+    fn with_transform(self, _transform: Option<Transform>) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     fn map<C, R>(self, relation: &R) -> <C as StateProvider>::StateMut<'a>
     where
         Self: Sized,
@@ -187,7 +263,19 @@ where
         let (model, state) = self.split();
         let model = RefMut::map(model, |m| relation.relation_model_mut(m));
         let state = RefMut::map(state, |s| relation.relation_state_mut(s));
-        C::create_state_mut(model, state)
+        C::create_state_mut(model, state).with_transform(relation.input_transform())
+    }
+
+    /** Maps to the related value and sets it, the shorthand used by the derived `set_*` functions. */
+    fn map_set<C, R, S>(self, relation: &R, value: S)
+    where
+        Self: Sized,
+        T: Model + 'a,
+        C: FormValue + 'a,
+        R: ModelRelation<T, C>,
+        S: Into<Rc<str>>,
+    {
+        ValueStateMut::set(&mut self.map(relation), value);
     }
 }
 
@@ -199,6 +287,19 @@ where
     fn set_dirty(&mut self, value: bool);
 }
 
+/** Applies the transform taken by [`StateMut::with_transform`] to a value being set. */
+pub(crate) fn transformed(transform: Option<&Transform>, value: Rc<str>) -> Rc<str> {
+    match transform {
+        Some(transform) => transform.apply(&value).into(),
+        None => value,
+    }
+}
+
+/// Marks a value as touched, apart from [`ValueStateMut`] so its implementors don't need to.
+pub trait TouchStateMut {
+    fn set_touched(&mut self, value: bool);
+}
+
 macro_rules! impl_state_provider {
     ($($t:ty),*) => {
         $(
@@ -217,6 +318,7 @@ macro_rules! impl_state_provider {
                     FormValueState {
                         value: model,
                         field: state,
+                        transform: None,
                     }
                 }
             }
@@ -225,7 +327,7 @@ macro_rules! impl_state_provider {
 }
 
 impl_state_provider!(
-    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char, String
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
 );
 
 impl<T> StateProvider for Vec<T>
@@ -250,12 +352,246 @@ where
     }
 }
 
+impl<T, const N: usize> StateProvider for [T; N]
+where
+    T: StateProvider,
+{
+    type State = [T::State; N];
+    type StateMut<'a> = ArrayMut<'a, T, N> where T: 'a;
+
+    fn create_state(&self) -> Self::State {
+        std::array::from_fn(|index| self[index].create_state())
+    }
+
+    fn create_state_mut<'a>(
+        model: RefMut<'a, Self>,
+        state: RefMut<'a, Self::State>,
+    ) -> Self::StateMut<'a> {
+        ArrayMut {
+            values: model,
+            states: state,
+        }
+    }
+}
+
+macro_rules! impl_tuple_state_provider {
+    ($(($($t:ident $i:tt $elem:ident),+)),*) => {
+        $(
+            impl<$($t),+> StateProvider for ($($t,)+)
+            where
+                $($t: StateProvider,)+
+            {
+                type State = ($($t::State,)+);
+                type StateMut<'a> = TupleMut<'a, Self> where Self: 'a;
+
+                fn create_state(&self) -> Self::State {
+                    ($(self.$i.create_state(),)+)
+                }
+
+                fn create_state_mut<'a>(
+                    model: RefMut<'a, Self>,
+                    state: RefMut<'a, Self::State>,
+                ) -> Self::StateMut<'a> {
+                    TupleMut { model, state }
+                }
+            }
+
+            impl<'a, $($t),+> StateMut<'a, ($($t,)+)> for TupleMut<'a, ($($t,)+)>
+            where
+                $($t: StateProvider,)+
+            {
+                fn split(self) -> (RefMut<'a, ($($t,)+)>, RefMut<'a, ($($t::State,)+)>) {
+                    (self.model, self.state)
+                }
+            }
+
+            impl<'a, $($t),+> TupleMut<'a, ($($t,)+)>
+            where
+                $($t: StateProvider,)+
+            {
+                $(
+                    /** Returns the mutable state of the tuple element with the same index. */
+                    pub fn $elem(self) -> $t::StateMut<'a> {
+                        let model = RefMut::map(self.model, |m| &mut m.$i);
+                        let state = RefMut::map(self.state, |s| &mut s.$i);
+                        $t::create_state_mut(model, state)
+                    }
+                )+
+            }
+        )*
+    };
+}
+
+impl_tuple_state_provider!(
+    (A 0 elem_0, B 1 elem_1),
+    (A 0 elem_0, B 1 elem_1, C 2 elem_2),
+    (A 0 elem_0, B 1 elem_1, C 2 elem_2, D 3 elem_3)
+);
+
+/// The state of a set, holding a [`Field`] for each value keyed by its string value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SetState {
+    fields: BTreeMap<String, Field>,
+    initial: BTreeSet<String>,
+    dirty: bool,
+}
+
+impl SetState {
+    pub(crate) fn new(values: impl IntoIterator<Item = String>) -> Self {
+        let fields = values
+            .into_iter()
+            .map(|v| (v.clone(), Field::new(v)))
+            .collect::<BTreeMap<_, _>>();
+
+        Self {
+            initial: fields.keys().cloned().collect(),
+            fields,
+            dirty: false,
+        }
+    }
+
+    pub fn contains(&self, value: &str) -> bool {
+        self.fields.contains_key(value)
+    }
+
+    pub fn get(&self, value: &str) -> Option<&Field> {
+        self.fields.get(value)
+    }
+
+    pub(crate) fn get_mut(&mut self, value: &str) -> Option<&mut Field> {
+        self.fields.get_mut(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Field)> {
+        self.fields
+            .iter()
+            .map(|(value, field)| (value.as_str(), field))
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Field)> {
+        self.fields
+            .iter_mut()
+            .map(|(value, field)| (value.as_str(), field))
+    }
+
+    /** Returns whether values were added or removed, or any of the values is dirty. */
+    pub fn dirty(&self) -> bool {
+        self.dirty || self.fields.values().any(Field::dirty)
+    }
+
+    /** Returns whether `value` was added or removed since the state was created. */
+    pub fn changed(&self, value: &str) -> bool {
+        self.fields.contains_key(value) != self.initial.contains(value)
+    }
+
+    pub fn valid(&self) -> bool {
+        self.fields.values().all(Field::valid)
+    }
+
+    pub(crate) fn insert(&mut self, value: String) -> bool {
+        if self.fields.contains_key(&value) {
+            return false;
+        }
+
+        self.fields.insert(value.clone(), Field::new(value));
+        self.dirty = true;
+        true
+    }
+
+    pub(crate) fn remove(&mut self, value: &str) -> Option<Field> {
+        let field = self.fields.remove(value)?;
+        self.dirty = true;
+        Some(field)
+    }
+
+    /** Moves the field of `value` to `new_value`, keeping its flags but replacing any field there. */
+    pub(crate) fn rename(&mut self, value: &str, new_value: &str) {
+        if let Some(mut field) = self.remove(value) {
+            field.set_value(new_value);
+            self.fields.insert(new_value.to_owned(), field);
+        }
+    }
+}
+
+pub struct SetMut<'a, S>
+where
+    S: StateProvider<State = SetState>,
+{
+    pub(crate) model: RefMut<'a, S>,
+    pub(crate) state: RefMut<'a, SetState>,
+}
+
+impl<'a, S> StateMut<'a, S> for SetMut<'a, S>
+where
+    S: StateProvider<State = SetState>,
+{
+    fn split(self) -> (RefMut<'a, S>, RefMut<'a, SetState>) {
+        (self.model, self.state)
+    }
+}
+
+macro_rules! impl_set_state_provider {
+    ($($set:ident: $($bound:path),+);*) => {
+        $(
+            impl<T> StateProvider for $set<T>
+            where
+                T: FormValue $(+ $bound)+,
+            {
+                type State = SetState;
+                type StateMut<'a> = SetMut<'a, Self> where T: 'a;
+
+                fn create_state(&self) -> Self::State {
+                    SetState::new(self.iter().map(FormValue::value))
+                }
+
+                fn create_state_mut<'a>(
+                    model: RefMut<'a, Self>,
+                    state: RefMut<'a, Self::State>,
+                ) -> Self::StateMut<'a> {
+                    SetMut { model, state }
+                }
+            }
+
+            impl<'a, T> SetMut<'a, $set<T>>
+            where
+                T: FormValue $(+ $bound)+,
+            {
+                pub fn contains(&self, value: &T) -> bool {
+                    self.model.contains(value)
+                }
+
+                pub fn insert(&mut self, value: T) -> bool {
+                    self.state.insert(value.value());
+                    self.model.insert(value)
+                }
+
+                pub fn remove(&mut self, value: &T) -> bool {
+                    self.state.remove(&value.value());
+                    self.model.remove(value)
+                }
+
+                /** Inserts the value when it's missing and removes it otherwise, returns whether it's now contained. */
+                pub fn toggle(&mut self, value: T) -> bool {
+                    match self.contains(&value) {
+                        true => !self.remove(&value),
+                        false => self.insert(value),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_set_state_provider!(HashSet: Eq, Hash; BTreeSet: Ord);
+
 pub struct OptionStateMut<'a, T>
 where
     T: StateProvider + Default,
 {
     model: RefMut<'a, Option<T>>,
     state: RefMut<'a, T::State>,
+    transform: Option<Transform>,
 }
 
 impl<'a, T> StateMut<'a, Option<T>> for OptionStateMut<'a, T>
@@ -271,21 +607,29 @@ where
     ) {
         (self.model, self.state)
     }
+
+    fn with_transform(mut self, transform: Option<Transform>) -> Self {
+        self.transform = transform;
+        self
+    }
 }
 
 impl<'a, T> ValueStateMut<'a, Option<T>> for OptionStateMut<'a, T>
 where
     T: FormValue + Default,
 {
+    /** Sets the value after applying the transform of the field, an empty value sets [`None`]. */
     fn set<S: Into<Rc<str>>>(&mut self, value: S) {
-        let value = value.into();
+        let value = transformed(self.transform.as_ref(), value.into());
         match value.is_empty() {
             true => {
                 *self.model = None;
                 self.state.set_value("");
             }
             false => {
-                *self.model = Some(T::from_value(&value));
+                if let Some(model) = T::from_value(&value) {
+                    *self.model = Some(model);
+                }
                 self.state.set_value(value);
             }
         }
@@ -296,6 +640,15 @@ where
     }
 }
 
+impl<'a, T> TouchStateMut for OptionStateMut<'a, T>
+where
+    T: StateProvider<State = Field> + Default,
+{
+    fn set_touched(&mut self, value: bool) {
+        self.state.set_touched(value);
+    }
+}
+
 impl<T> StateProvider for Option<T>
 where
     T: StateProvider + Default,
@@ -317,6 +670,10 @@ where
         model: RefMut<'a, Self>,
         state: RefMut<'a, Self::State>,
     ) -> Self::StateMut<'a> {
-        OptionStateMut { model, state }
+        OptionStateMut {
+            model,
+            state,
+            transform: None,
+        }
     }
 }
@@ -2,7 +2,10 @@ use std::{cell::RefMut, ops::Deref, rc::Rc, str::FromStr};
 
 use crate::{
     field::Field,
-    form_state::{OptionStateMut, StateMut, StateProvider, ValueStateMut},
+    form_state::{
+        transformed, OptionStateMut, StateMut, StateProvider, TouchStateMut, ValueStateMut,
+    },
+    transform::Transform,
 };
 
 pub trait FormValue
@@ -11,12 +14,14 @@ where
     for<'a> Self:
         StateProvider<State = Field, StateMut<'a> = <Self as FormValue>::StateMut<'a>> + 'a,
 {
-    type StateMut<'a>: ValueStateMut<'a, Self>
+    // Repeats the `StateMut` bound of `StateProvider`, which isn't implied for this type
+    type StateMut<'a>: ValueStateMut<'a, Self> + StateMut<'a, Self>
     where
         Self: 'a;
 
     fn value(&self) -> String;
-    fn from_value(value: &str) -> Self;
+    /** Parses the value of a control, [`None`] for text that isn't a value, e.g. partial input like `-`. */
+    fn from_value(value: &str) -> Option<Self>;
 }
 
 pub struct FormValueState<'a, T>
@@ -25,6 +30,8 @@ where
 {
     pub(crate) value: RefMut<'a, T>,
     pub(crate) field: RefMut<'a, T::State>,
+    /// The transform of the field applied by [`ValueStateMut::set`]
+    pub(crate) transform: Option<Transform>,
 }
 
 impl<'a, T> Deref for FormValueState<'a, T>
@@ -45,15 +52,23 @@ where
     fn split(self) -> (RefMut<'a, T>, RefMut<'a, T::State>) {
         (self.value, self.field)
     }
+
+    fn with_transform(mut self, transform: Option<Transform>) -> Self {
+        self.transform = transform;
+        self
+    }
 }
 
 impl<'a, T> ValueStateMut<'a, T> for FormValueState<'a, T>
 where
     T: FormValue,
 {
+    /** Sets the value after applying the transform of the field, the model keeps its value when it doesn't parse. */
     fn set<S: Into<Rc<str>>>(&mut self, value: S) {
-        let value = value.into();
-        *self.value = FormValue::from_value(&value);
+        let value = transformed(self.transform.as_ref(), value.into());
+        if let Some(model) = FormValue::from_value(&value) {
+            *self.value = model;
+        }
         self.field.set_value(value);
     }
 
@@ -62,6 +77,15 @@ where
     }
 }
 
+impl<'a, T> TouchStateMut for FormValueState<'a, T>
+where
+    T: StateProvider<State = Field>,
+{
+    fn set_touched(&mut self, value: bool) {
+        self.field.set_touched(value);
+    }
+}
+
 macro_rules! impl_form_value {
     ($($t:ty),*) => {
         $(
@@ -72,8 +96,8 @@ macro_rules! impl_form_value {
                     self.to_string()
                 }
 
-                fn from_value(value: &str) -> Self {
-                    value.parse().unwrap()
+                fn from_value(value: &str) -> Option<Self> {
+                    value.parse().ok()
                 }
             }
         )*
@@ -81,9 +105,69 @@ macro_rules! impl_form_value {
 }
 
 impl_form_value!(
-    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char, String
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
 );
 
+/// A numeric [`FormValue`], as bound by [`NumberInput`](crate::NumberInput) and [`RangeInput`](crate::RangeInput).
+///
+/// Inputs read the number through `valueAsNumber`, which doesn't depend on the decimal separator of the locale.
+pub trait Numeric: FormValue + PartialOrd + Copy {
+    /** Converts from the number of an input, `None` if it's not a number or doesn't fit the type. */
+    fn from_f64(value: f64) -> Option<Self>;
+
+    /** The value of an empty input, `None` when the type needs a number. */
+    fn empty() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn from_f64(value: f64) -> Option<Self> {
+                    // `MAX as f64` rounds up for the wide types, the power of two after it doesn't
+                    let end = 2f64.powi((<$t>::BITS - u32::from(<$t>::MIN != 0)) as i32);
+
+                    (value.fract() == 0.0 && value >= <$t>::MIN as f64 && value < end)
+                        .then_some(value as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn from_f64(value: f64) -> Option<Self> {
+                    (!value.is_nan()).then_some(value as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_float!(f32, f64);
+
+/// Optional numbers are [`None`] while the input is empty.
+impl<T> Numeric for Option<T>
+where
+    T: Numeric + Default,
+    Self: FormValue,
+{
+    fn from_f64(value: f64) -> Option<Self> {
+        T::from_f64(value).map(Some)
+    }
+
+    fn empty() -> Option<Self> {
+        Some(None)
+    }
+}
+
 impl<T> FormValue for Option<T>
 where
     T: FormValue + Default + 'static,
@@ -95,11 +179,11 @@ where
         self.as_ref().map(FormValue::value).unwrap_or_default()
     }
 
-    fn from_value(value: &str) -> Self {
+    fn from_value(value: &str) -> Option<Self> {
         if value.is_empty() {
-            None
+            Some(None)
         } else {
-            Some(FormValue::from_value(value))
+            FormValue::from_value(value).map(Some)
         }
     }
 }
@@ -152,6 +236,7 @@ where
         FormValueState {
             value: model,
             field: state,
+            transform: None,
         }
     }
 }
@@ -159,7 +244,6 @@ where
 impl<T> FormValue for ValueWrapper<T>
 where
     T: FromStr + ToString + PartialEq + 'static,
-    <T as FromStr>::Err: std::fmt::Debug,
 {
     type StateMut<'a>
     = FormValueState<'a, ValueWrapper<T>>
@@ -170,7 +254,7 @@ where
         self.0.to_string()
     }
 
-    fn from_value(value: &str) -> Self {
-        ValueWrapper(T::from_str(value).unwrap())
+    fn from_value(value: &str) -> Option<Self> {
+        T::from_str(value).ok().map(ValueWrapper)
     }
 }
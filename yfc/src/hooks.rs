@@ -1,6 +1,11 @@
 use yew::prelude::*;
 
-use crate::{form::Form, form_state::OwnedFormState, model::Model};
+use crate::{
+    components::form_field::FieldDescription,
+    form::Form,
+    form_state::{OwnedFormState, StateProvider},
+    model::Model,
+};
 
 #[hook]
 pub fn use_form<T>(init_fn: impl FnOnce() -> T) -> Form<T>
@@ -20,3 +25,18 @@ where
     let form_state = use_memo(|d| OwnedFormState::new(init_fn(d)), deps);
     Form::with_state(form_state, counter)
 }
+
+/** Returns the form of the closest [`FormProvider`](crate::FormProvider) or [`FormScope`](crate::FormScope) for `T`. */
+#[hook]
+pub fn use_form_context<T>() -> Option<Form<T>>
+where
+    T: StateProvider + 'static,
+{
+    use_context::<Form<T>>()
+}
+
+/** Returns the ids describing the control of the enclosing [`FormField`](crate::FormField), for `aria-describedby`. */
+#[hook]
+pub fn use_described_by() -> Option<AttrValue> {
+    use_context::<FieldDescription>().and_then(|description| description.described_by)
+}
@@ -1,13 +1,31 @@
+pub mod bindings;
 pub mod components;
+pub mod dyn_field;
 pub mod field;
+pub mod files;
+#[cfg(feature = "bitflags")]
+pub mod flags;
 pub mod form;
 pub mod form_state;
 pub mod form_value;
 pub mod hooks;
+pub mod mask;
+pub mod meta;
 pub mod model;
+pub mod multi_value;
+pub mod path;
 pub mod prelude;
+pub mod theme;
+pub mod transform;
+pub mod visit;
 
 pub use components::*;
+pub use dyn_field::DynField;
+pub use files::Files;
+#[cfg(feature = "bitflags")]
+pub use flags::FlagSet;
 pub use form::Form;
+pub use meta::FieldMeta;
 pub use model::Model;
+pub use path::FieldPath;
 pub use yfc_derive::Model;
@@ -0,0 +1,209 @@
+use std::{fmt::Debug, rc::Rc};
+
+use yew::html::IntoPropValue;
+
+/// Maps between the value stored in a field and the value displayed in an input, e.g. the digits
+/// `5551234567` of a phone number displayed as `(555) 123-4567`.
+///
+/// A mask is given to an [`Input`](crate::Input) with its `mask` prop or with the
+/// `#[yfc(mask = "...")]` attribute of a field, where a string is a [`PatternMask`].
+pub trait Mask: Debug {
+    /** Returns the displayed value of a stored value. */
+    fn format(&self, stored: &str) -> String;
+
+    /** Returns the stored value of a displayed, possibly partially typed, value. */
+    fn parse(&self, display: &str) -> String;
+
+    /** Masks the value typed into an input, where `caret` is the position of the caret in characters. */
+    fn input(&self, display: &str, caret: usize) -> MaskedInput {
+        let stored = self.parse(display);
+        let before_caret = display.chars().take(caret).collect::<String>();
+        let caret = self.format(&self.parse(&before_caret)).chars().count();
+        let display = self.format(&stored);
+
+        MaskedInput {
+            caret: caret.min(display.chars().count()),
+            stored,
+            display,
+        }
+    }
+}
+
+/// The result of [`Mask::input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskedInput {
+    pub stored: String,
+    pub display: String,
+    /// The position of the caret in `display`, after the characters that were before it
+    pub caret: usize,
+}
+
+/// A mask from a pattern, e.g. `(999) 999-9999` for phone numbers or `AA99 9999 9999 99` for IBANs.
+///
+/// The placeholders are:
+/// - `9` a digit
+/// - `a` a letter
+/// - `A` a letter, stored in uppercase
+/// - `*` a letter or digit
+///
+/// Any other character of the pattern is only displayed. The stored value holds the characters of
+/// the placeholders, characters that don't fit the pattern are dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMask {
+    pattern: Vec<char>,
+}
+
+impl PatternMask {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.chars().collect(),
+        }
+    }
+
+    /** Returns `c` converted the way it's stored when it fits `slot`, [`None`] when `slot` is a literal. */
+    fn fit(slot: char, c: char) -> Option<Option<char>> {
+        match slot {
+            '9' => Some(c.is_ascii_digit().then_some(c)),
+            'a' => Some(c.is_alphabetic().then_some(c)),
+            'A' => Some(c.is_alphabetic().then(|| c.to_ascii_uppercase())),
+            '*' => Some(c.is_alphanumeric().then_some(c)),
+            _ => None,
+        }
+    }
+
+    fn is_slot(slot: char) -> bool {
+        matches!(slot, '9' | 'a' | 'A' | '*')
+    }
+}
+
+impl Mask for PatternMask {
+    /// Literals are only displayed up to the last filled placeholder, so the caret stays after the
+    /// last typed character.
+    fn format(&self, stored: &str) -> String {
+        let mut display = String::new();
+        let mut pending = String::new();
+        let mut chars = stored.chars().peekable();
+
+        for &slot in &self.pattern {
+            if chars.peek().is_none() {
+                break;
+            }
+
+            if !Self::is_slot(slot) {
+                pending.push(slot);
+                continue;
+            }
+
+            // Skip the characters that don't fit this placeholder
+            for c in chars.by_ref() {
+                if let Some(Some(c)) = Self::fit(slot, c) {
+                    display.push_str(&pending);
+                    display.push(c);
+                    pending.clear();
+                    break;
+                }
+            }
+        }
+
+        display
+    }
+
+    /// Literals of the pattern are skipped when they're typed, so a literal that looks like a
+    /// placeholder, e.g. the `1` of `+1 (999) 999-9999`, isn't stored.
+    fn parse(&self, display: &str) -> String {
+        let mut stored = String::new();
+        let mut position = 0;
+
+        'chars: for c in display.chars() {
+            while let Some(&literal) = self.pattern.get(position) {
+                if Self::is_slot(literal) {
+                    break;
+                }
+                position += 1;
+
+                if literal == c {
+                    continue 'chars;
+                }
+            }
+
+            let Some(&slot) = self.pattern.get(position) else {
+                break;
+            };
+
+            if let Some(Some(c)) = Self::fit(slot, c) {
+                stored.push(c);
+                position += 1;
+            }
+        }
+
+        stored
+    }
+}
+
+impl From<&str> for PatternMask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl IntoPropValue<Option<DynMask>> for PatternMask {
+    fn into_prop_value(self) -> Option<DynMask> {
+        Some(DynMask::new(self))
+    }
+}
+
+/// A shared [`Mask`], compared by reference.
+#[derive(Clone)]
+pub struct DynMask(Rc<dyn Mask>);
+
+impl DynMask {
+    pub fn new<M: Mask + 'static>(mask: M) -> Self {
+        Self(Rc::new(mask))
+    }
+}
+
+impl<M: Mask + 'static> From<M> for DynMask {
+    fn from(mask: M) -> Self {
+        Self::new(mask)
+    }
+}
+
+impl std::ops::Deref for DynMask {
+    type Target = dyn Mask;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl PartialEq for DynMask {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for DynMask {}
+
+impl Debug for DynMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/** Converts a position in UTF-16 code units, as used by the DOM, to a position in characters. */
+pub(crate) fn utf16_to_chars(value: &str, position: usize) -> usize {
+    let mut units = 0;
+
+    value
+        .chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= position
+        })
+        .count()
+}
+
+/** Converts a position in characters to a position in UTF-16 code units. */
+pub(crate) fn chars_to_utf16(value: &str, position: usize) -> usize {
+    value.chars().take(position).map(char::len_utf16).sum()
+}
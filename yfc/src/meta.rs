@@ -0,0 +1,133 @@
+use yew::AttrValue;
+
+use crate::{mask::DynMask, transform::Transform};
+
+/// Describes the field a [`Form`](crate::Form) is seeded from.
+///
+/// The name is the path of the field from the root form, e.g. `address.city` or `items[3]`, and
+/// is used for the `name` and `id` of the rendered elements. The other values come from the
+/// `#[yfc(label = "...", placeholder = "...", help = "...", mask = "...", transform = ...,
+/// required)]` attributes of the field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldMeta {
+    name: AttrValue,
+    label: Option<AttrValue>,
+    placeholder: Option<AttrValue>,
+    help: Option<AttrValue>,
+    mask: Option<DynMask>,
+    transform: Option<Transform>,
+    required: bool,
+}
+
+impl FieldMeta {
+    pub fn new(name: impl Into<AttrValue>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<AttrValue>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<AttrValue>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<AttrValue>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_mask(mut self, mask: impl Into<DynMask>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+
+    pub fn with_transform(mut self, transform: impl Into<Transform>) -> Self {
+        self.transform = Some(transform.into());
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /** Returns the name of the field, [`None`] for the root form. */
+    pub fn name(&self) -> Option<AttrValue> {
+        (!self.name.is_empty()).then(|| self.name.clone())
+    }
+
+    /** Returns the name made safe to use as an element id, e.g. `items[3].price` becomes `items-3-price`. */
+    pub fn id(&self) -> Option<AttrValue> {
+        let mut id = String::with_capacity(self.name.len());
+
+        for c in self.name.chars() {
+            match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => id.push(c),
+                _ if !id.is_empty() && !id.ends_with('-') => id.push('-'),
+                _ => {}
+            }
+        }
+
+        while id.ends_with('-') {
+            id.pop();
+        }
+
+        (!id.is_empty()).then(|| id.into())
+    }
+
+    pub fn label(&self) -> Option<&AttrValue> {
+        self.label.as_ref()
+    }
+
+    pub fn placeholder(&self) -> Option<&AttrValue> {
+        self.placeholder.as_ref()
+    }
+
+    pub fn help(&self) -> Option<&AttrValue> {
+        self.help.as_ref()
+    }
+
+    pub fn mask(&self) -> Option<&DynMask> {
+        self.mask.as_ref()
+    }
+
+    pub fn transform(&self) -> Option<&Transform> {
+        self.transform.as_ref()
+    }
+
+    /** Returns the transform to apply whenever the value is set, the one that doesn't wait for the blur. */
+    pub(crate) fn input_transform(&self) -> Option<&Transform> {
+        self.transform
+            .as_ref()
+            .filter(|transform| !transform.is_on_blur())
+    }
+
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    /** Prefixes the name with the name of `parent`, used by the derive to name nested fields. */
+    pub fn nested_in(mut self, parent: &FieldMeta) -> Self {
+        self.name = match (parent.name.is_empty(), self.name.is_empty()) {
+            (true, _) => self.name,
+            (false, true) => parent.name.clone(),
+            (false, false) if self.name.starts_with('[') => {
+                format!("{}{}", parent.name, self.name).into()
+            }
+            (false, false) => format!("{}.{}", parent.name, self.name).into(),
+        };
+        self
+    }
+
+    /** Appends `name` to the name and keeps the rest, e.g. for the elements of a list field. */
+    pub(crate) fn extended(mut self, name: impl Into<AttrValue>) -> Self {
+        self.name = FieldMeta::new(name).nested_in(&self).name;
+        self
+    }
+}
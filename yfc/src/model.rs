@@ -1,4 +1,12 @@
-use crate::{form_state::StateProvider, prelude::FormValue};
+use std::{
+    collections::{BTreeSet, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    form_state::StateProvider, form_value::ValueWrapper, meta::FieldMeta, prelude::FormValue,
+    transform::Transform,
+};
 
 pub trait Model
 where
@@ -15,6 +23,195 @@ where
     fn relation_model_mut<'a>(&self, parent: &'a mut P) -> &'a mut C;
     fn relation_state<'a>(&self, parent: &'a P::State) -> &'a C::State;
     fn relation_state_mut<'a>(&self, parent: &'a mut P::State) -> &'a mut C::State;
+
+    /** Describes the related field, a form seeded with this relation exposes it as [`Form::meta`](crate::Form::meta). */
+    fn meta(&self) -> FieldMeta {
+        FieldMeta::default()
+    }
+
+    /** The transform of [`meta`](Self::meta) applied whenever the value is set, built without the rest of the metadata. */
+    fn input_transform(&self) -> Option<Transform> {
+        None
+    }
+}
+
+/// Names the parent and child of a [`ModelRelation`], implemented by the derive for its relations.
+///
+/// This allows components like [`FormScope`](crate::FormScope) to be generic over just the relation.
+pub trait TypedRelation: Clone + PartialEq {
+    type Parent: Model;
+    type Child: StateProvider;
 }
 
 impl<T> Model for T where T: FormValue {}
+
+/// Lists are models of their elements, related by [`ElemRelation`].
+impl<T> Model for Vec<T> where T: StateProvider {}
+
+/// Relates a list to its element at an index, used by [`Form::elem_form`](crate::Form::elem_form).
+///
+/// The index isn't updated when elements are inserted or removed, so forms of elements are
+/// seeded again after changing the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElemRelation(pub usize);
+
+impl<T> ModelRelation<Vec<T>, T> for ElemRelation
+where
+    T: StateProvider,
+{
+    fn relation_model<'a>(&self, parent: &'a Vec<T>) -> &'a T {
+        &parent[self.0]
+    }
+
+    fn relation_model_mut<'a>(&self, parent: &'a mut Vec<T>) -> &'a mut T {
+        &mut parent[self.0]
+    }
+
+    fn relation_state<'a>(&self, parent: &'a Vec<T::State>) -> &'a T::State {
+        &parent[self.0]
+    }
+
+    fn relation_state_mut<'a>(&self, parent: &'a mut Vec<T::State>) -> &'a mut T::State {
+        &mut parent[self.0]
+    }
+
+    fn meta(&self) -> FieldMeta {
+        FieldMeta::new(format!("[{}]", self.0))
+    }
+}
+
+/// The value a model is reset to by [`Form::reset`](crate::Form::reset) and
+/// [`ListMut::push_default`](crate::form_state::ListMut::push_default).
+///
+/// Derived models use the `#[yfc(default = ...)]` of a field when given.
+pub trait ModelDefault {
+    fn model_default() -> Self;
+}
+
+/// Resets a model in place for [`Form::reset`](crate::Form::reset).
+///
+/// Apart from [`ModelDefault`], as derived models keep the values of their `#[yfc(skip)]` fields
+/// and so don't need defaults for them.
+pub trait ModelReset {
+    fn reset_model(&mut self);
+}
+
+/// Implements [`ModelReset`] by replacing the value with its [`ModelDefault`].
+macro_rules! impl_model_reset_by_default {
+    ($([$($generics:tt)*] $t:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> ModelReset for $t
+            where
+                $t: ModelDefault,
+            {
+                fn reset_model(&mut self) {
+                    *self = Self::model_default();
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_model_reset_by_default;
+
+macro_rules! impl_model_default {
+    ($($t:ty),*) => {
+        $(
+            impl ModelDefault for $t {
+                fn model_default() -> Self {
+                    Default::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_model_default!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
+);
+
+impl_model_reset_by_default!(
+    [] u8, [] u16, [] u32, [] u64, [] u128, [] usize, [] i8, [] i16, [] i32, [] i64, [] i128,
+    [] isize, [] f32, [] f64, [] bool, [] char, [] String,
+    [T] Option<T>, [T] Vec<T>, [T] HashSet<T>, [T] BTreeSet<T>,
+    [T: FromStr + ToString] ValueWrapper<T>,
+);
+
+impl<T> ModelDefault for Option<T> {
+    fn model_default() -> Self {
+        None
+    }
+}
+
+impl<T> ModelDefault for Vec<T> {
+    fn model_default() -> Self {
+        Vec::new()
+    }
+}
+
+impl<T> ModelDefault for HashSet<T> {
+    fn model_default() -> Self {
+        HashSet::new()
+    }
+}
+
+impl<T> ModelDefault for BTreeSet<T> {
+    fn model_default() -> Self {
+        BTreeSet::new()
+    }
+}
+
+impl<T, const N: usize> ModelDefault for [T; N]
+where
+    T: ModelDefault,
+{
+    fn model_default() -> Self {
+        std::array::from_fn(|_| T::model_default())
+    }
+}
+
+impl<T, const N: usize> ModelReset for [T; N]
+where
+    T: ModelReset,
+{
+    fn reset_model(&mut self) {
+        self.iter_mut().for_each(ModelReset::reset_model);
+    }
+}
+
+impl<T> ModelDefault for ValueWrapper<T>
+where
+    T: FromStr + ToString + Default,
+{
+    fn model_default() -> Self {
+        ValueWrapper(T::default())
+    }
+}
+
+macro_rules! impl_tuple_model_default {
+    ($(($($t:ident),+)),*) => {
+        $(
+            impl<$($t),+> ModelDefault for ($($t,)+)
+            where
+                $($t: ModelDefault,)+
+            {
+                fn model_default() -> Self {
+                    ($($t::model_default(),)+)
+                }
+            }
+
+            impl<$($t),+> ModelReset for ($($t,)+)
+            where
+                $($t: ModelReset,)+
+            {
+                #[allow(non_snake_case)]
+                fn reset_model(&mut self) {
+                    let ($($t,)+) = self;
+                    $($t.reset_model();)+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_model_default!((A, B), (A, B, C), (A, B, C, D));
@@ -0,0 +1,114 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+};
+
+use crate::{
+    field::Field,
+    form_state::{SetState, StateProvider},
+    form_value::FormValue,
+};
+
+/// A model holding any number of values, like a `Vec` or a set, as bound by
+/// [`MultiSelect`](crate::MultiSelect).
+pub trait MultiValue: StateProvider {
+    fn values(&self) -> Vec<String>;
+
+    fn contains_value(&self, value: &str) -> bool {
+        self.values().iter().any(|v| v == value)
+    }
+
+    /** Replaces the values of the model, keeping the state of values that remain. */
+    fn set_values(model: &mut Self, state: &mut Self::State, values: &[String]);
+    fn dirty(state: &Self::State) -> bool;
+    fn valid(state: &Self::State) -> bool;
+
+    /** Returns whether `value` was added, removed or edited, used to mark single options. */
+    fn value_dirty(state: &Self::State, value: &str) -> bool;
+}
+
+impl<T> MultiValue for Vec<T>
+where
+    T: FormValue,
+{
+    fn values(&self) -> Vec<String> {
+        self.iter().map(FormValue::value).collect()
+    }
+
+    fn set_values(model: &mut Self, state: &mut Vec<Field>, values: &[String]) {
+        let mut previous = std::mem::take(state);
+
+        for value in values {
+            let field = match previous.iter().position(|f| f.value() == value) {
+                Some(index) => previous.remove(index),
+                None => {
+                    let mut field = Field::new(value);
+                    field.set_dirty(true);
+                    field
+                }
+            };
+            state.push(field);
+        }
+
+        *model = values.iter().filter_map(|v| T::from_value(v)).collect();
+    }
+
+    fn dirty(state: &Vec<Field>) -> bool {
+        state.iter().any(Field::dirty)
+    }
+
+    fn valid(state: &Vec<Field>) -> bool {
+        state.iter().all(Field::valid)
+    }
+
+    fn value_dirty(state: &Vec<Field>, value: &str) -> bool {
+        state.iter().any(|f| f.value() == value && f.dirty())
+    }
+}
+
+macro_rules! impl_set_multi_value {
+    ($($set:ident: $($bound:path),+);*) => {
+        $(
+            impl<T> MultiValue for $set<T>
+            where
+                T: FormValue $(+ $bound)+,
+            {
+                fn values(&self) -> Vec<String> {
+                    self.iter().map(FormValue::value).collect()
+                }
+
+                fn set_values(model: &mut Self, state: &mut SetState, values: &[String]) {
+                    let removed = state
+                        .iter()
+                        .map(|(value, _)| value.to_owned())
+                        .filter(|value| !values.contains(value))
+                        .collect::<Vec<_>>();
+
+                    for value in removed {
+                        state.remove(&value);
+                    }
+
+                    for value in values {
+                        state.insert(value.clone());
+                    }
+
+                    *model = values.iter().filter_map(|v| T::from_value(v)).collect();
+                }
+
+                fn dirty(state: &SetState) -> bool {
+                    state.dirty()
+                }
+
+                fn valid(state: &SetState) -> bool {
+                    state.valid()
+                }
+
+                fn value_dirty(state: &SetState, value: &str) -> bool {
+                    state.changed(value) || state.get(value).is_some_and(Field::dirty)
+                }
+            }
+        )*
+    };
+}
+
+impl_set_multi_value!(HashSet: Eq, Hash; BTreeSet: Ord);
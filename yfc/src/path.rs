@@ -0,0 +1,151 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use yew::AttrValue;
+
+use crate::form_value::FormValue;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Field(AttrValue),
+    Index(usize),
+}
+
+/// The path to a field relative to a form, e.g. `address.city` or `items[3].price`.
+///
+/// Paths are either built from the typed paths generated for each model, e.g.
+/// `Order::path().items_elem(3).price()`, or parsed from a string.
+///
+/// The fields of a model are named by their Rust identifier, so a `postal_code` field renamed
+/// with `#[yfc(name = "zip")]` is still found at `address.postal_code`. The `name` only affects
+/// the [`FieldMeta`](crate::meta::FieldMeta), which names the rendered elements.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: impl Into<AttrValue>) -> Self {
+        self.push(PathSegment::Field(name.into()));
+        self
+    }
+
+    pub fn index(mut self, index: usize) -> Self {
+        self.push(PathSegment::Index(index));
+        self
+    }
+
+    pub fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        self.0.pop()
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// The string isn't a valid path, holds the offending input.
+    Invalid(String),
+    /// No field exists at the path.
+    NotFound(FieldPath),
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::Invalid(path) => write!(f, "`{}` is not a valid field path", path),
+            PathError::NotFound(path) => write!(f, "no field found at `{}`", path),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl FromStr for FieldPath {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PathError::Invalid(s.to_owned());
+        let is_name_char = |c: char| c.is_alphanumeric() || c == '_';
+        let mut path = FieldPath::new();
+        let mut rest = s;
+
+        while !rest.is_empty() {
+            if let Some(index) = rest.strip_prefix('[') {
+                let (index, remainder) = index.split_once(']').ok_or_else(invalid)?;
+                path.push(PathSegment::Index(index.parse().map_err(|_| invalid())?));
+                rest = remainder;
+            } else {
+                let name = match rest.strip_prefix('.') {
+                    Some(name) if !path.is_empty() => name,
+                    None if path.is_empty() => rest,
+                    _ => return Err(invalid()),
+                };
+                let end = name.find(|c| !is_name_char(c)).unwrap_or(name.len());
+
+                if end == 0 {
+                    return Err(invalid());
+                }
+
+                path.push(PathSegment::Field(name[..end].to_owned().into()));
+                rest = &name[end..];
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+/// Links a type to its typed path, derived models get a generated `{Model}Path`.
+pub trait HasPath {
+    type Path: From<FieldPath> + Into<FieldPath>;
+
+    /** Returns the typed path to the root of this model. */
+    fn path() -> Self::Path {
+        FieldPath::new().into()
+    }
+}
+
+impl<T> HasPath for T
+where
+    T: FormValue,
+{
+    type Path = FieldPath;
+}
+
+impl<T> HasPath for HashSet<T> {
+    type Path = FieldPath;
+}
+
+impl<T> HasPath for BTreeSet<T> {
+    type Path = FieldPath;
+}
@@ -1,9 +1,14 @@
 pub use yfc_derive::Model;
 
 pub use crate::{
+    bindings::{use_checkbox_binding, use_input_binding},
+    components::combobox::use_combobox,
     form::Form,
-    form_state::ValueStateMut,
-    form_value::{FormValue, ValueWrapper},
-    hooks::{use_form, use_form_with_deps},
+    form_state::{TouchStateMut, ValueStateMut},
+    form_value::{FormValue, Numeric, ValueWrapper},
+    hooks::{use_form, use_form_context, use_form_with_deps},
     model::Model,
+    path::HasPath,
+    theme::{use_part_classes, Theme, ThemeContext},
+    visit::Visit,
 };
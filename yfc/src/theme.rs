@@ -0,0 +1,242 @@
+use std::{ops::Deref, rc::Rc};
+
+use yew::prelude::*;
+
+/// The parts of a form a [`Theme`] provides classes for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    Input,
+    TextArea,
+    Select,
+    Checkbox,
+    Radio,
+    Range,
+    File,
+    /// The wrapper of a [`RadioGroup`](crate::RadioGroup) or [`CheckboxGroup`](crate::CheckboxGroup)
+    Group,
+    Label,
+    /// The error message of a [`FormField`](crate::FormField)
+    Error,
+    /// The help text of a [`FormField`](crate::FormField)
+    Help,
+    /// The list of invalid fields of an [`ErrorSummary`](crate::ErrorSummary)
+    ErrorSummary,
+}
+
+/// The class names of every part of a form, provided to the components by a [`ThemeProvider`](crate::ThemeProvider).
+///
+/// The classes given to a component as props replace the ones of the theme.
+pub trait Theme {
+    fn classes(&self, part: Part) -> Classes;
+
+    /** Added once the field is dirty and valid. */
+    fn valid(&self, _part: Part) -> Classes {
+        Classes::new()
+    }
+
+    /** Added once the field is dirty and invalid. */
+    fn invalid(&self, _part: Part) -> Classes {
+        Classes::new()
+    }
+}
+
+/// A theme without any classes, e.g. to opt a part of the page out of the provided theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoTheme;
+
+impl Theme for NoTheme {
+    fn classes(&self, _part: Part) -> Classes {
+        Classes::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bootstrap;
+
+impl Theme for Bootstrap {
+    fn classes(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::File => classes!("form-control"),
+            Part::Select => classes!("form-select"),
+            Part::Checkbox | Part::Radio => classes!("form-check-input"),
+            Part::Range => classes!("form-range"),
+            Part::Group => Classes::new(),
+            Part::Label => classes!("form-label"),
+            Part::Error => classes!("invalid-feedback", "d-block"),
+            Part::Help => classes!("form-text"),
+            Part::ErrorSummary => classes!("alert", "alert-danger"),
+        }
+    }
+
+    fn valid(&self, part: Part) -> Classes {
+        match part {
+            Part::Label | Part::Error | Part::Help | Part::ErrorSummary => Classes::new(),
+            _ => classes!("is-valid"),
+        }
+    }
+
+    fn invalid(&self, part: Part) -> Classes {
+        match part {
+            Part::Label | Part::Error | Part::Help | Part::ErrorSummary => Classes::new(),
+            _ => classes!("is-invalid"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bulma;
+
+impl Theme for Bulma {
+    fn classes(&self, part: Part) -> Classes {
+        match part {
+            Part::Input => classes!("input"),
+            Part::TextArea => classes!("textarea"),
+            Part::File => classes!("file-input"),
+            Part::Checkbox => classes!("checkbox"),
+            Part::Radio => classes!("radio"),
+            Part::Select | Part::Range | Part::Group => Classes::new(),
+            Part::Label => classes!("label"),
+            Part::Error => classes!("help", "is-danger"),
+            Part::Help => classes!("help"),
+            Part::ErrorSummary => classes!("notification", "is-danger"),
+        }
+    }
+
+    fn valid(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::Select => classes!("is-success"),
+            _ => Classes::new(),
+        }
+    }
+
+    fn invalid(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::Select => classes!("is-danger"),
+            _ => Classes::new(),
+        }
+    }
+}
+
+/// Utility classes in the style of the Tailwind UI forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tailwind;
+
+impl Theme for Tailwind {
+    fn classes(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::Select => classes!(
+                "block",
+                "w-full",
+                "rounded-md",
+                "border-gray-300",
+                "shadow-sm",
+                "focus:border-indigo-500",
+                "focus:ring-indigo-500",
+            ),
+            Part::Checkbox => classes!(
+                "h-4",
+                "w-4",
+                "rounded",
+                "border-gray-300",
+                "text-indigo-600"
+            ),
+            Part::Radio => classes!("h-4", "w-4", "border-gray-300", "text-indigo-600"),
+            Part::Range => classes!("w-full", "accent-indigo-600"),
+            Part::File => classes!("block", "w-full", "text-sm", "text-gray-900"),
+            Part::Group => classes!("space-y-2"),
+            Part::Label => classes!("block", "text-sm", "font-medium", "text-gray-700"),
+            Part::Error => classes!("mt-2", "text-sm", "text-red-600"),
+            Part::Help => classes!("mt-2", "text-sm", "text-gray-500"),
+            Part::ErrorSummary => classes!("rounded-md", "bg-red-50", "p-4", "text-red-800"),
+        }
+    }
+
+    fn valid(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::Select => classes!("border-green-500"),
+            _ => Classes::new(),
+        }
+    }
+
+    fn invalid(&self, part: Part) -> Classes {
+        match part {
+            Part::Input | Part::TextArea | Part::Select => {
+                classes!("border-red-500", "text-red-900")
+            }
+            _ => Classes::new(),
+        }
+    }
+}
+
+/// A shared [`Theme`], as provided by a [`ThemeProvider`](crate::ThemeProvider).
+#[derive(Clone)]
+pub struct ThemeContext(Rc<dyn Theme>);
+
+impl ThemeContext {
+    pub fn new<T: Theme + 'static>(theme: T) -> Self {
+        Self(Rc::new(theme))
+    }
+}
+
+impl Deref for ThemeContext {
+    type Target = dyn Theme;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl PartialEq for ThemeContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for ThemeContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ThemeContext")
+    }
+}
+
+/// The classes of a part, resolved from the props of a component and the theme.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PartClasses {
+    pub classes: Classes,
+    pub valid: Classes,
+    pub invalid: Classes,
+}
+
+impl PartClasses {
+    /** Returns the classes with the valid or invalid ones added once the field is dirty. */
+    pub fn with_state(&self, dirty: bool, valid: bool) -> Classes {
+        classes!(
+            self.classes.clone(),
+            dirty.then(|| match valid {
+                true => self.valid.clone(),
+                false => self.invalid.clone(),
+            })
+        )
+    }
+}
+
+/** Returns the classes of `part`, where the non-empty `classes`, `valid` and `invalid` replace the ones of the theme. */
+#[hook]
+pub fn use_part_classes(
+    part: Part,
+    classes: &Classes,
+    valid: &Classes,
+    invalid: &Classes,
+) -> PartClasses {
+    let theme = use_context::<ThemeContext>();
+    let resolve =
+        |prop: &Classes, themed: fn(&dyn Theme, Part) -> Classes| match (&theme, prop.is_empty()) {
+            (Some(theme), true) => themed(&**theme, part),
+            _ => prop.clone(),
+        };
+
+    PartClasses {
+        classes: resolve(classes, |theme, part| theme.classes(part)),
+        valid: resolve(valid, |theme, part| theme.valid(part)),
+        invalid: resolve(invalid, |theme, part| theme.invalid(part)),
+    }
+}
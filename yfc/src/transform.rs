@@ -0,0 +1,111 @@
+use std::ops::BitOr;
+
+use unicode_normalization::UnicodeNormalization;
+use yew::html::IntoPropValue;
+
+/// A step of a [`Transform`].
+#[derive(Debug, Clone, Copy)]
+pub enum Step {
+    Trim,
+    Lowercase,
+    Uppercase,
+    /// Unicode normalization form C, so equal text is stored the same way
+    Nfc,
+    Fn(fn(&str) -> String),
+}
+
+impl PartialEq for Step {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Functions are compared by address, which is only a best effort
+            (Step::Fn(a), Step::Fn(b)) => *a as usize == *b as usize,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Step {}
+
+impl Step {
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            Step::Trim => value.trim().to_owned(),
+            Step::Lowercase => value.to_lowercase(),
+            Step::Uppercase => value.to_uppercase(),
+            Step::Nfc => value.nfc().collect(),
+            Step::Fn(f) => f(value),
+        }
+    }
+}
+
+/// The steps applied to the value of a field before it's set, or once the field loses focus.
+///
+/// Set with the `#[yfc(transform = trim | lowercase)]` or `#[yfc(transform_on_blur = trim)]`
+/// attributes of a field, where a function path is a [`Step::Fn`], or the `transform` prop of a
+/// component. Steps are combined with `|`, e.g. `Step::Trim | Step::Lowercase`.
+///
+/// Trimming while typing removes the space before the next word, so it usually runs on blur.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transform {
+    steps: Vec<Step>,
+    on_blur: bool,
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn then(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /** Applies the transform once the field loses focus instead of on every input. */
+    pub fn on_blur(mut self) -> Self {
+        self.on_blur = true;
+        self
+    }
+
+    pub fn is_on_blur(&self) -> bool {
+        self.on_blur
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn apply(&self, value: &str) -> String {
+        self.steps
+            .iter()
+            .fold(value.to_owned(), |value, step| step.apply(&value))
+    }
+}
+
+impl From<Step> for Transform {
+    fn from(step: Step) -> Self {
+        Transform::new().then(step)
+    }
+}
+
+impl IntoPropValue<Option<Transform>> for Step {
+    fn into_prop_value(self) -> Option<Transform> {
+        Some(self.into())
+    }
+}
+
+impl BitOr for Step {
+    type Output = Transform;
+
+    fn bitor(self, rhs: Step) -> Self::Output {
+        Transform::from(self).then(rhs)
+    }
+}
+
+impl BitOr<Step> for Transform {
+    type Output = Transform;
+
+    fn bitor(self, rhs: Step) -> Self::Output {
+        self.then(rhs)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    field::Field,
+    form_state::SetState,
+    path::{FieldPath, PathSegment},
+};
+
+pub trait FieldVisitor {
+    fn visit(&mut self, path: &FieldPath, field: &Field);
+}
+
+pub trait FieldVisitorMut {
+    fn visit_mut(&mut self, path: &FieldPath, field: &mut Field);
+}
+
+impl<F> FieldVisitor for F
+where
+    F: FnMut(&FieldPath, &Field),
+{
+    fn visit(&mut self, path: &FieldPath, field: &Field) {
+        self(path, field)
+    }
+}
+
+impl<F> FieldVisitorMut for F
+where
+    F: FnMut(&FieldPath, &mut Field),
+{
+    fn visit_mut(&mut self, path: &FieldPath, field: &mut Field) {
+        self(path, field)
+    }
+}
+
+/// Walks every leaf [`Field`] of a state, implemented by the derive for the generated states.
+///
+/// Fields are visited in declaration order with their path, e.g. `items[3].price`.
+pub trait Visit {
+    /** Visits all fields, with their paths prefixed by `path`. */
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V);
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V);
+
+    fn visit<V: FieldVisitor>(&self, visitor: &mut V) {
+        self.visit_at(&mut FieldPath::new(), visitor);
+    }
+
+    /** Visits all fields mutably, e.g. to mark them all as touched. */
+    fn visit_mut<V: FieldVisitorMut>(&mut self, visitor: &mut V) {
+        self.visit_mut_at(&mut FieldPath::new(), visitor);
+    }
+}
+
+/// Visits `value` with `segment` pushed onto the path, used by the derive for every field.
+pub fn visit_segment<T, V>(value: &T, segment: PathSegment, path: &mut FieldPath, visitor: &mut V)
+where
+    T: Visit + ?Sized,
+    V: FieldVisitor,
+{
+    path.push(segment);
+    value.visit_at(path, visitor);
+    path.pop();
+}
+
+/// The mutable version of [`visit_segment`].
+pub fn visit_segment_mut<T, V>(
+    value: &mut T,
+    segment: PathSegment,
+    path: &mut FieldPath,
+    visitor: &mut V,
+) where
+    T: Visit + ?Sized,
+    V: FieldVisitorMut,
+{
+    path.push(segment);
+    value.visit_mut_at(path, visitor);
+    path.pop();
+}
+
+impl Visit for Field {
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+        visitor.visit(path, self);
+    }
+
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+        visitor.visit_mut(path, self);
+    }
+}
+
+impl<T> Visit for [T]
+where
+    T: Visit,
+{
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+        for (index, elem) in self.iter().enumerate() {
+            visit_segment(elem, PathSegment::Index(index), path, visitor);
+        }
+    }
+
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+        for (index, elem) in self.iter_mut().enumerate() {
+            visit_segment_mut(elem, PathSegment::Index(index), path, visitor);
+        }
+    }
+}
+
+impl<T> Visit for Vec<T>
+where
+    T: Visit,
+{
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+        self.as_slice().visit_at(path, visitor);
+    }
+
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+        self.as_mut_slice().visit_mut_at(path, visitor);
+    }
+}
+
+impl<T, const N: usize> Visit for [T; N]
+where
+    T: Visit,
+{
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+        self.as_slice().visit_at(path, visitor);
+    }
+
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+        self.as_mut_slice().visit_mut_at(path, visitor);
+    }
+}
+
+/// The values of a set are named by their string value, e.g. `tags.rust`.
+impl Visit for SetState {
+    fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+        for (value, field) in self.iter() {
+            visit_segment(
+                field,
+                PathSegment::Field(value.to_owned().into()),
+                path,
+                visitor,
+            );
+        }
+    }
+
+    fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+        for (value, field) in self.iter_mut() {
+            visit_segment_mut(
+                field,
+                PathSegment::Field(value.to_owned().into()),
+                path,
+                visitor,
+            );
+        }
+    }
+}
+
+macro_rules! impl_tuple_visit {
+    ($(($($t:ident $i:tt),+)),*) => {
+        $(
+            impl<$($t),+> Visit for ($($t,)+)
+            where
+                $($t: Visit,)+
+            {
+                fn visit_at<V: FieldVisitor>(&self, path: &mut FieldPath, visitor: &mut V) {
+                    $(
+                        visit_segment(&self.$i, PathSegment::Field(stringify!($i).into()), path, visitor);
+                    )+
+                }
+
+                fn visit_mut_at<V: FieldVisitorMut>(&mut self, path: &mut FieldPath, visitor: &mut V) {
+                    $(
+                        visit_segment_mut(&mut self.$i, PathSegment::Field(stringify!($i).into()), path, visitor);
+                    )+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_visit!((A 0, B 1), (A 0, B 1, C 2), (A 0, B 1, C 2, D 3));
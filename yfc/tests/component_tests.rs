@@ -3,7 +3,7 @@ mod common;
 #[cfg(target_arch = "wasm32")]
 use std::time::Duration;
 
-use common::render;
+use common::{render, use_once};
 // use validator::Validate;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::test;
@@ -72,7 +72,7 @@ async fn test_checkbox() {
         let html = render::<Test>().await;
         assert_eq!(
             html,
-            r#"<input value="true" checked type="checkbox"><div id="value">true</div><div id="model">true</div>"#
+            r#"<input value="true" checked id="check" name="check" type="checkbox"><div id="value">true</div><div id="model">true</div>"#
         );
     }
 }
@@ -135,7 +135,895 @@ async fn test_input() {
         let html = render::<Test>().await;
         assert_eq!(
             html,
-            r#"<input value="test" type="text" autocomplete="off" placeholder=""><div id="value">test</div><div id="model">test</div>"#
+            r#"<input value="test" id="input" name="input" type="text" autocomplete="off"><div id="value">test</div><div id="model">test</div>"#
         );
     }
 }
+
+#[test]
+async fn test_field_meta() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        address: Address,
+    }
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Address {
+        #[yfc(label = "City", placeholder = "Brussels", name = "town")]
+        city: String,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model {
+            address: Address {
+                city: "Ghent".into(),
+            },
+        });
+        let city = form.address_form().city_form();
+
+        html! {
+            <>
+                <Label<String> form={city.clone()}/>
+                <Input<String> form={city}/>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        r#"<label id="address-town-label" for="address-town">City</label><input value="Ghent" id="address-town" name="address.town" type="text" autocomplete="off" placeholder="Brussels">"#
+    );
+}
+
+#[test]
+async fn test_form_field() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(label = "Name", help = "Your full name")]
+        name: String,
+        email: String,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model {
+            name: "Jane".into(),
+            email: "jane@example.com".into(),
+        });
+        let name = form.name_form();
+        let email = form.email_form();
+        let unnamed = use_form(String::new);
+
+        html! {
+            <>
+                <FormField<String> form={name.clone()}>
+                    <Input<String> form={name}/>
+                </FormField<String>>
+                <FormField<String> form={email} label="Email" help="Never shared">
+                    <input type="email"/>
+                </FormField<String>>
+                <FormField<String> form={unnamed} help="Not referred to">
+                    <input type="text"/>
+                </FormField<String>>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        r#"<div><label id="name-label" for="name">Name</label><input value="Jane" id="name" name="name" type="text" autocomplete="off" aria-describedby="name-help"><p id="name-help">Your full name</p></div><div><label id="email-label" for="email">Email</label><input type="email" aria-describedby="email-help"><p id="email-help">Never shared</p></div><div><input type="text"><p>Not referred to</p></div>"#
+    );
+}
+
+#[test]
+async fn test_aria_attributes() {
+    use yfc::Files;
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(required, help = "Your full name")]
+        name: String,
+        #[yfc(required)]
+        bio: String,
+        agree: bool,
+        kind: String,
+        #[yfc(required)]
+        upload: Files,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            name: "".into(),
+            bio: "".into(),
+            agree: false,
+            kind: "a".into(),
+            upload: Files::default(),
+        });
+        let name = form.name_form();
+
+        use_once({
+            let form = form.clone();
+            move || form.at_str("name").unwrap().set_valid(false)
+        })?;
+
+        Ok(html! {
+            <>
+                <FormField<String> form={name.clone()}>
+                    <Input<String> form={name}/>
+                </FormField<String>>
+                <TextArea<String> form={form.bio_form()} oninput={Callback::noop()}/>
+                <Checkbox form={form.agree_form()}/>
+                <Select<String> form={form.kind_form()}>
+                    <SelectOption value="a"/>
+                </Select<String>>
+                <File form={form.upload_form()}/>
+            </>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div><input value="" id="name" name="name" type="text" autocomplete="off" aria-invalid="true" aria-required="true" aria-describedby="name-help"><p id="name-help">Your full name</p></div>"#,
+            r#"<textarea id="bio" name="bio" cols="20" rows="5" wrap="soft" spellcheck="false" autocomplete="false" autocorrect="false" aria-required="true"></textarea>"#,
+            r#"<input value="false" id="agree" name="agree" type="checkbox">"#,
+            r#"<select id="kind" name="kind" autocomplete="off"><option value="a" selected="selected">a</option></select>"#,
+            r#"<input id="upload" type="file" name="upload" accept="" aria-required="true" capture="">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_radio_group() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(required)]
+        size: String,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model { size: "m".into() });
+        let unnamed = use_form(|| String::from("b"));
+
+        html! {
+            <>
+                <RadioGroup<String> form={form.size_form()}>
+                    <Radio value="s">{"Small"}</Radio>
+                    <Radio value="m">{"Medium"}</Radio>
+                    <Radio value="l" disabled=true/>
+                </RadioGroup<String>>
+                <RadioGroup<String> form={unnamed} name="choice">
+                    <Radio value="a"/>
+                    <Radio value="b"/>
+                </RadioGroup<String>>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div role="radiogroup" aria-labelledby="size-label" aria-required="true">"#,
+            r#"<label><input value="s" type="radio" name="size">Small</label>"#,
+            r#"<label><input value="m" checked type="radio" name="size">Medium</label>"#,
+            r#"<label><input value="l" type="radio" name="size" disabled="disabled">l</label>"#,
+            r#"</div>"#,
+            r#"<div role="radiogroup">"#,
+            r#"<label><input value="a" type="radio" name="choice">a</label>"#,
+            r#"<label><input value="b" checked type="radio" name="choice">b</label>"#,
+            r#"</div>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_multi_select() {
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        tags: BTreeSet<String>,
+        sizes: Vec<u32>,
+        colors: HashSet<String>,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            tags: ["rust".to_owned()].into(),
+            sizes: vec![1, 3],
+            colors: HashSet::new(),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.colors_form()
+                    .set_values(&["red".to_owned(), "blue".to_owned()]);
+                form.colors_form().state_mut().remove(&"blue".to_owned());
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <MultiSelect<BTreeSet<String>> form={form.tags_form()}>
+                    <SelectOption value="rust"/>
+                    <SelectOption value="yew"/>
+                </MultiSelect<BTreeSet<String>>>
+                <MultiSelect<Vec<u32>> form={form.sizes_form()} size={2}>
+                    <SelectOption value="1"/>
+                    <SelectOption value="2"/>
+                    <SelectOption value="3"/>
+                </MultiSelect<Vec<u32>>>
+                <p>{form.state().colors.dirty()}{form.model().colors.len()}</p>
+                <p>{form.at_str("colors.red").unwrap().value()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<select id="tags" name="tags" autocomplete="off" multiple="multiple">"#,
+            r#"<option value="rust" selected="selected">rust</option><option value="yew">yew</option>"#,
+            r#"</select>"#,
+            r#"<select id="sizes" name="sizes" size="2" autocomplete="off" multiple="multiple">"#,
+            r#"<option value="1" selected="selected">1</option><option value="2">2</option><option value="3" selected="selected">3</option>"#,
+            r#"</select>"#,
+            r#"<p>true1</p><p>red</p>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_checkbox_group() {
+    use std::collections::BTreeSet;
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        permissions: BTreeSet<String>,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            permissions: ["read".to_owned(), "write".to_owned()].into(),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.permissions_form().toggle_value("write");
+                form.permissions_form().toggle_value("admin");
+            }
+        })?;
+
+        Ok(html! {
+            <CheckboxGroup<BTreeSet<String>> form={form.permissions_form()} select_all="All" classes_dirty="changed">
+                <CheckboxOption value="read">{"Read"}</CheckboxOption>
+                <CheckboxOption value="write">{"Write"}</CheckboxOption>
+                <CheckboxOption value="admin"/>
+            </CheckboxGroup<BTreeSet<String>>>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div id="permissions" role="group">"#,
+            r#"<label><input value="" type="checkbox">All</label>"#,
+            r#"<label><input value="read" checked type="checkbox" name="permissions">Read</label>"#,
+            r#"<label class="changed"><input value="write" type="checkbox" name="permissions">Write</label>"#,
+            r#"<label class="changed"><input value="admin" checked type="checkbox" name="permissions">admin</label>"#,
+            r#"</div>"#,
+        )
+    );
+}
+
+#[cfg(feature = "bitflags")]
+#[test]
+async fn test_checkbox_group_flags() {
+    use yfc::FlagSet;
+
+    bitflags::bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Permissions: u8 {
+            const READ = 1;
+            const WRITE = 2;
+        }
+    }
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        permissions: FlagSet<Permissions>,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            permissions: FlagSet(Permissions::READ),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.permissions_form().state_mut().select_all();
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <CheckboxGroup<FlagSet<Permissions>> form={form.permissions_form()} select_all="All">
+                    <CheckboxOption value="READ"/>
+                    <CheckboxOption value="WRITE"/>
+                </CheckboxGroup<FlagSet<Permissions>>>
+                <p>{form.model().permissions.bits()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div id="permissions" role="group">"#,
+            r#"<label><input value="" checked type="checkbox">All</label>"#,
+            r#"<label><input value="READ" checked type="checkbox" name="permissions">READ</label>"#,
+            r#"<label><input value="WRITE" checked type="checkbox" name="permissions">WRITE</label>"#,
+            r#"</div>"#,
+            r#"<p>3</p>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_tri_state_and_value_checkbox() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        newsletter: Option<bool>,
+        terms: Option<bool>,
+        active: String,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model {
+            newsletter: None,
+            terms: Some(true),
+            active: "Y".to_owned(),
+        });
+
+        html! {
+            <>
+                <TriStateCheckbox form={form.newsletter_form()}/>
+                <TriStateCheckbox form={form.terms_form()}/>
+                <ValueCheckbox<String> form={form.active_form()} checked_value="Y" unchecked_value="N"/>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<input id="newsletter" name="newsletter" type="checkbox" aria-checked="mixed">"#,
+            r#"<input checked id="terms" name="terms" type="checkbox">"#,
+            r#"<input value="Y" checked id="active" name="active" type="checkbox">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_number_input() {
+    use yfc::form_value::Numeric;
+
+    // The bounds are exact, even where `MAX as f64` rounds up
+    assert_eq!(u32::from_f64(4294967295.0), Some(u32::MAX));
+    assert_eq!(u64::from_f64(18446744073709551615.0), None);
+    assert_eq!(i64::from_f64(-9223372036854775808.0), Some(i64::MIN));
+    assert_eq!(i64::from_f64(9223372036854775807.0), None);
+    assert_eq!(u8::from_f64(1.5), None);
+    assert_eq!(u8::empty(), None);
+    assert_eq!(Option::<u8>::empty(), Some(None));
+    assert_eq!(Option::<u8>::from_f64(7.0), Some(Some(7)));
+    assert_eq!(
+        NumberError::TooLarge { max: Some(10u32) }.to_string(),
+        "Must be at most 10"
+    );
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        quantity: u32,
+        price: f64,
+        volume: u8,
+        discount: Option<u32>,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model {
+            quantity: 3,
+            price: 9.5,
+            volume: 40,
+            discount: None,
+        });
+
+        html! {
+            <>
+                <NumberInput<u32> form={form.quantity_form()} min={1} max={10}/>
+                <NumberInput<f64> form={form.price_form()} step={0.5}/>
+                <RangeInput<u8> form={form.volume_form()} max={100}/>
+                <NumberInput<Option<u32>> form={form.discount_form()} max={Some(50)}/>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<input value="3" id="quantity" name="quantity" type="number" min="1" max="10">"#,
+            r#"<input value="9.5" id="price" name="price" type="number" step="0.5">"#,
+            r#"<input value="40" id="volume" name="volume" type="range" max="100">"#,
+            r#"<input value="" id="discount" name="discount" type="number" max="50">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_input_type() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        name: String,
+        age: Option<u8>,
+        meeting: String,
+    }
+
+    #[function_component(Test)]
+    pub fn test() -> Html {
+        let form = use_form(|| Model {
+            name: "yew".to_owned(),
+            age: None,
+            meeting: "2023-03-14T09:30".to_owned(),
+        });
+
+        html! {
+            <>
+                <Input<String> form={form.name_form()}/>
+                <Input<Option<u8>> form={form.age_form()}/>
+                <Input<String> form={form.meeting_form()} input_type={InputType::DatetimeLocal}/>
+            </>
+        }
+    }
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<input value="yew" id="name" name="name" type="text" autocomplete="off">"#,
+            r#"<input value="" id="age" name="age" type="number" autocomplete="off">"#,
+            r#"<input value="2023-03-14T09:30" id="meeting" name="meeting" type="datetime-local" autocomplete="off">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_input_binding() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(required, placeholder = "Your name")]
+        name: String,
+        subscribe: bool,
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct CustomProps {
+        form: Form<String>,
+    }
+
+    #[function_component(CustomInput)]
+    fn custom_input(CustomProps { form }: &CustomProps) -> Html {
+        let binding = use_input_binding(form);
+
+        html! {
+            <input
+                id={binding.id.clone()}
+                class={classes!("custom", binding.state_classes(&"ok".into(), &"error".into()))}
+                placeholder={binding.placeholder.clone()}
+                value={binding.value.clone()}
+                aria-invalid={binding.aria_invalid.clone()}
+                aria-required={binding.aria_required.clone()}
+                oninput={binding.oninput.clone()}
+                onblur={binding.onblur.clone()}
+            />
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct CustomToggleProps {
+        form: Form<bool>,
+    }
+
+    #[function_component(CustomToggle)]
+    fn custom_toggle(CustomToggleProps { form }: &CustomToggleProps) -> Html {
+        let binding = use_checkbox_binding(form);
+
+        html! {
+            <button role="switch" aria-checked={binding.checked.to_string()}>{binding.name.clone()}</button>
+        }
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            name: "".into(),
+            subscribe: false,
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.name_form().state_mut().set("Jane");
+                form.at_str("name").unwrap().set_valid(false);
+                form.subscribe_form().state_mut().set("true");
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <CustomInput form={form.name_form()}/>
+                <CustomToggle form={form.subscribe_form()}/>
+            </>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<input value="Jane" id="name" placeholder="Your name" aria-invalid="true" aria-required="true" class="custom error">"#,
+            r#"<button role="switch" aria-checked="true">subscribe</button>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_theme() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        name: String,
+        email: String,
+        accepted: bool,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let theme = use_memo(|_| ThemeContext::new(yfc::theme::Bootstrap), ());
+        let form = use_form(|| Model {
+            name: "".into(),
+            email: "".into(),
+            accepted: false,
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.name_form().state_mut().set("Jane");
+                form.email_form().state_mut().set("jane");
+                form.at_str("email").unwrap().set_valid(false);
+            }
+        })?;
+
+        Ok(html! {
+            <ThemeProvider theme={(*theme).clone()}>
+                <Label<String> form={form.name_form()}/>
+                <Input<String> form={form.name_form()}/>
+                <Input<String> form={form.email_form()} classes="custom" classes_invalid="error"/>
+                <Checkbox form={form.accepted_form()}/>
+            </ThemeProvider>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<label id="name-label" for="name" class="form-label"></label>"#,
+            r#"<input value="Jane" id="name" name="name" type="text" autocomplete="off" class="form-control is-valid">"#,
+            r#"<input value="jane" id="email" name="email" type="text" autocomplete="off" aria-invalid="true" class="custom error">"#,
+            r#"<input value="false" id="accepted" name="accepted" type="checkbox" class="form-check-input">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_textarea_length() {
+    use yfc::LengthError;
+
+    assert_eq!(
+        LengthError::check(3, Some(5), Some(10)),
+        Some(LengthError::TooShort { min: 5 })
+    );
+    assert_eq!(LengthError::check(5, Some(5), Some(10)), None);
+    assert_eq!(
+        LengthError::check(11, None, Some(10)).unwrap().to_string(),
+        "Must be at most 10 characters"
+    );
+
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        bio: String,
+        notes: String,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> Html {
+        let form = use_form(|| Model {
+            // Counted in UTF-16 code units like `maxlength`, so the emoji counts twice
+            bio: "Hello\nWorld \u{1f44b}".into(),
+            notes: "a\nb\nc\nd\ne\nf\ng".into(),
+        });
+
+        html! {
+            <>
+                <TextArea<String> form={form.bio_form()} oninput={Callback::noop()} maxlength={140} counter=true/>
+                <TextArea<String> form={form.notes_form()} oninput={Callback::noop()} rows={2} auto_grow=true max_rows={6} counter=true/>
+            </>
+        }
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<textarea id="bio" name="bio" cols="20" rows="5" maxlength="140" wrap="soft" spellcheck="false" autocomplete="false" autocorrect="false" aria-describedby="bio-counter">Hello"#,
+            "\n",
+            "World \u{1f44b}",
+            r#"</textarea><p id="bio-counter" aria-live="polite">14 / 140</p>"#,
+            r#"<textarea id="notes" name="notes" cols="20" rows="6" wrap="soft" spellcheck="false" autocomplete="false" autocorrect="false" aria-describedby="notes-counter">a"#,
+            "\nb\nc\nd\ne\nf\ng",
+            r#"</textarea><p id="notes-counter" aria-live="polite">13</p>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_input_mask() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(mask = "(999) 999-9999")]
+        phone: String,
+        postal_code: String,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> Html {
+        let form = use_form(|| Model {
+            phone: "5551234567".into(),
+            postal_code: "K1A0B1".into(),
+        });
+
+        html! {
+            <>
+                <Input<String> form={form.phone_form()}/>
+                <Input<String> form={form.postal_code_form()} mask={yfc::mask::PatternMask::new("A9A 9A9")}/>
+            </>
+        }
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<input value="(555) 123-4567" id="phone" name="phone" type="text" autocomplete="off">"#,
+            r#"<input value="K1A 0B1" id="postal_code" name="postal_code" type="text" autocomplete="off">"#,
+        )
+    );
+}
+
+#[test]
+async fn test_combobox() {
+    #[derive(Clone, PartialEq, Model)]
+    struct Model {
+        #[yfc(placeholder = "Pick a fruit")]
+        fruit: String,
+        city: String,
+        color: String,
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ChipsProps {
+        form: Form<String>,
+        options: Vec<AttrValue>,
+    }
+
+    #[function_component(Chips)]
+    fn chips(ChipsProps { form, options }: &ChipsProps) -> Html {
+        let binding = use_combobox(form, None, options, None);
+
+        html! {
+            <p id={binding.listbox_id.clone()}>
+                {for binding.suggestions.iter().enumerate().map(|(index, suggestion)| html! {
+                    <span id={binding.option_id(index)}>{suggestion}</span>
+                })}
+            </p>
+        }
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> Html {
+        let form = use_form(|| Model {
+            fruit: "ap".into(),
+            city: "".into(),
+            color: "re".into(),
+        });
+        let fruits: Vec<AttrValue> = vec!["Apple".into(), "Banana".into(), "Grape".into()];
+        let unnamed = use_form(String::new);
+
+        html! {
+            <>
+                <Combobox<String> form={form.fruit_form()} options={fruits}/>
+                <Combobox<String> form={form.city_form()} options={vec![AttrValue::from("Ghent")]} datalist=true/>
+                <Chips form={form.color_form()} options={vec![AttrValue::from("Red"), "Green".into(), "Blue".into()]}/>
+                <Combobox<String> form={unnamed} id="search" datalist=true/>
+            </>
+        }
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div><input value="ap" id="fruit" name="fruit" type="text" role="combobox" autocomplete="off" aria-autocomplete="list" aria-expanded="false" aria-controls="fruit-listbox" placeholder="Pick a fruit">"#,
+            r#"<ul id="fruit-listbox" role="listbox" hidden="hidden">"#,
+            r#"<li id="fruit-listbox-0" role="option" aria-selected="false">Apple</li>"#,
+            r#"<li id="fruit-listbox-1" role="option" aria-selected="false">Grape</li>"#,
+            r#"</ul></div>"#,
+            r#"<input value="" id="city" name="city" type="text" list="city-listbox">"#,
+            r#"<datalist id="city-listbox"><option value="Ghent"></option></datalist>"#,
+            r#"<p id="color-listbox"><span id="color-listbox-0">Red</span><span id="color-listbox-1">Green</span></p>"#,
+            r#"<input value="" id="search" type="text" list="search-listbox"><datalist id="search-listbox"></datalist>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_list_editor() {
+    #[derive(Model)]
+    struct Model {
+        items: Vec<Item>,
+    }
+
+    #[derive(Model)]
+    struct Item {
+        name: String,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> Html {
+        let form = use_form(|| Model {
+            items: vec![Item { name: "a".into() }, Item { name: "b".into() }],
+        });
+        let row = Callback::from(|ListRow { form, index }: ListRow<Item>| {
+            html! {
+                <>
+                    <span>{index}</span>
+                    <Input<String> form={form.name_form()}/>
+                </>
+            }
+        });
+
+        html! {
+            <ListEditor<Item> form={form.items_form()} {row} min={1} max={2} classes_button="button"/>
+        }
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div><div><span>0</span><input value="a" id="items-0-name" name="items[0].name" type="text" autocomplete="off">"#,
+            r#"<button type="button" disabled="disabled" class="button">Move up</button>"#,
+            r#"<button type="button" class="button">Move down</button>"#,
+            r#"<button type="button" class="button">Remove</button></div>"#,
+            r#"<div><span>1</span><input value="b" id="items-1-name" name="items[1].name" type="text" autocomplete="off">"#,
+            r#"<button type="button" class="button">Move up</button>"#,
+            r#"<button type="button" disabled="disabled" class="button">Move down</button>"#,
+            r#"<button type="button" class="button">Remove</button></div>"#,
+            r#"<button type="button" disabled="disabled" class="button">Add</button></div>"#,
+        )
+    );
+}
+
+#[test]
+async fn test_error_summary() {
+    #[derive(Model)]
+    struct Model {
+        name: String,
+        #[yfc(name = "mail", label = "Email address")]
+        email: String,
+        items: Vec<Item>,
+    }
+
+    #[derive(Model)]
+    struct Item {
+        price: u32,
+    }
+
+    #[function_component(Comp)]
+    pub fn comp() -> HtmlResult {
+        let form = use_form(|| Model {
+            name: "".into(),
+            email: "".into(),
+            items: vec![Item { price: 1 }, Item { price: 2 }],
+        });
+        let valid = use_form(|| Model {
+            name: "".into(),
+            email: "".into(),
+            items: vec![],
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.at_str("name").unwrap().set_valid(false);
+                form.at_str("email").unwrap().set_valid(false);
+                form.at_str("items[1].price").unwrap().set_valid(false);
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <ErrorSummary<Model> form={form.clone()} id="not-submitted"/>
+                <ErrorSummary<Model> form={form.clone()} submitted={1}/>
+                <ErrorSummary<Model> form={valid} submitted={1}/>
+                <ErrorSummary<Vec<Item>> form={form.items_form()} submitted={2} id="items-errors" title="Check the items"/>
+            </>
+        })
+    }
+
+    create_test_comp!(Comp);
+
+    let html = render::<Test>().await;
+    assert_eq!(
+        html,
+        concat!(
+            r#"<div id="error-summary" role="alert" tabindex="-1" aria-labelledby="error-summary-title">"#,
+            r#"<h2 id="error-summary-title">There is a problem</h2>"#,
+            r##"<ul><li><a href="#name">name</a></li><li><a href="#mail">Email address</a></li>"##,
+            r##"<li><a href="#items-1-price">items[1].price</a></li></ul></div>"##,
+            r#"<div id="items-errors" role="alert" tabindex="-1" aria-labelledby="items-errors-title">"#,
+            r#"<h2 id="items-errors-title">Check the items</h2>"#,
+            r##"<ul><li><a href="#items-1-price">items[1].price</a></li></ul></div>"##,
+        )
+    );
+}
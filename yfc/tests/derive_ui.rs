@@ -0,0 +1,13 @@
+// The snapshots list the implementors of some traits, which the optional features add to
+#[cfg(not(any(
+    target_arch = "wasm32",
+    feature = "validator",
+    feature = "serde",
+    feature = "bitflags",
+    feature = "ybc"
+)))]
+#[test]
+fn derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -43,6 +43,71 @@ async fn test_value() {
     assert_eq!(render::<Test>().await, "<p>42</p><p>42</p>");
 }
 
+#[test]
+async fn test_form_eq() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Model {
+        value: u32,
+    }
+
+    #[function_component(Component)]
+    fn component() -> Html {
+        let form = use_form(|| Model { value: 0 });
+        let value = form.value_form();
+        let labelled = value.clone().map_meta(|meta| meta.with_label("Value"));
+
+        html! {
+            <>
+                <p>{value == value.clone()}</p>
+                <p>{value == labelled}</p>
+            </>
+        }
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(render::<Test>().await, "<p>true</p><p>false</p>");
+}
+
+#[test]
+async fn test_unparsed_value() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Model {
+        value: u32,
+        optional: Option<u32>,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model {
+            value: 42,
+            optional: Some(7),
+        });
+        use_once({
+            let form = form.clone();
+            move || {
+                form.state_mut().set_value("-");
+                form.state_mut().optional().set("1e");
+            }
+        })?;
+        Ok(html! {
+            <>
+                <p>{&form.state().value}</p>
+                <p>{form.model().value}</p>
+                <p>{&form.state().optional}</p>
+                <p>{format!("{:?}", form.model().optional)}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(
+        render::<Test>().await,
+        "<p>-</p><p>42</p><p>1e</p><p>Some(7)</p>"
+    );
+}
+
 #[test]
 async fn test_model_value() {
     #[derive(Model, Debug, PartialEq, Eq)]
@@ -173,3 +238,518 @@ async fn test_option_value_none() {
 
     assert_eq!(render::<Test>().await, "<p></p>");
 }
+
+#[test]
+async fn test_array_value() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Model {
+        rgb: [u8; 3],
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model { rgb: [0, 0, 0] });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.state_mut().set_rgb(0, "255");
+                form.state_mut().rgb().elem(2).set("42");
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <p>{form.state().rgb(0)}</p>
+                <p>{form.state().rgb(1)}</p>
+                <p>{form.model().rgb[2]}</p>
+                <p>{form.state().dirty()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(
+        render::<Test>().await,
+        "<p>255</p><p>0</p><p>42</p><p>true</p>"
+    );
+}
+
+#[test]
+async fn test_tuple_value() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Model {
+        range: (u32, u32),
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model { range: (1, 2) });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.state_mut().set_range_0("10");
+                form.state_mut().range().elem_1().set("20");
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <p>{&form.state().range.0}</p>
+                <p>{form.model().range.1}</p>
+                <p>{form.range_1_form().state().value()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(render::<Test>().await, "<p>10</p><p>20</p><p>20</p>");
+}
+
+#[test]
+async fn test_unannotated_fields() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Parent {
+        child: Child,
+        children: Vec<Child>,
+        values: Vec<u32>,
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Child {
+        value: u32,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Parent {
+            child: Child { value: 0 },
+            children: vec![Child { value: 1 }],
+            values: vec![2],
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.state_mut().child().set_value("42");
+                form.children_elem_form(0).state_mut().set_value("43");
+                form.state_mut().set_values(0, "44");
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <p>{&form.state().child.value}</p>
+                <p>{&form.state().children(0).value}</p>
+                <p>{form.model().values[0]}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(render::<Test>().await, "<p>42</p><p>43</p><p>44</p>");
+}
+
+#[test]
+async fn test_skip_and_default() {
+    struct Lookup;
+
+    #[derive(Model)]
+    struct Model {
+        #[yfc(default = 7)]
+        value: u32,
+        #[yfc(list)]
+        items: Vec<Item>,
+        #[yfc(skip, default = std::rc::Rc::new(Lookup))]
+        lookup: std::rc::Rc<Lookup>,
+        #[yfc(skip)]
+        id: u64,
+        // Without a `Default`, which `reset` doesn't need for skipped fields
+        #[yfc(skip)]
+        handle: std::rc::Rc<dyn Fn() -> u32>,
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Item {
+        #[yfc(default = "new".into())]
+        name: String,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model {
+            value: 1,
+            items: vec![],
+            lookup: std::rc::Rc::new(Lookup),
+            id: 42,
+            handle: std::rc::Rc::new(|| 3),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.reset();
+                form.state_mut().items().push_default();
+            }
+        })?;
+
+        Ok(html! {
+            <>
+                <p>{&form.state().value}</p>
+                <p>{&form.state().items(0).name}</p>
+                <p>{form.model().id}</p>
+                <p>{std::rc::Rc::strong_count(&form.model().lookup)}</p>
+                <p>{(form.model().handle)()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    // Skipped fields aren't form data, so `reset` keeps them
+    assert_eq!(
+        render::<Test>().await,
+        "<p>7</p><p>new</p><p>42</p><p>1</p><p>3</p>"
+    );
+}
+
+#[test]
+async fn test_field_paths() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Order {
+        address: Address,
+        items: Vec<Item>,
+        range: (u32, u32),
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Address {
+        city: String,
+        #[yfc(name = "zip", label = "Postal code", required)]
+        postal_code: String,
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Item {
+        price: u32,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Order {
+            address: Address {
+                city: "Ghent".into(),
+                postal_code: "9000".into(),
+            },
+            items: vec![Item { price: 1 }, Item { price: 2 }],
+            range: (0, 10),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.at(Order::path().address().city())
+                    .unwrap()
+                    .set("Brussels");
+                form.at(Order::path().items_elem(1).price())
+                    .unwrap()
+                    .set("42");
+                form.at_str("range.1").unwrap().set("20");
+                // Paths use the identifier, the name is only used for the metadata
+                form.address_form()
+                    .at_str("postal_code")
+                    .unwrap()
+                    .set("1000");
+            }
+        })?;
+
+        let price = form.at_str("items[1].price").unwrap();
+        let postal_code = form.at(Order::path().address().postal_code()).unwrap();
+
+        Ok(html! {
+            <>
+                <p>{form.model().address.city.clone()}</p>
+                <p>{form.model().address.postal_code.clone()}</p>
+                <p>{form.model().items[1].price}</p>
+                <p>{form.model().range.1}</p>
+                <p>{price.value()}{price.dirty()}{price.meta().id()}</p>
+                <p>{postal_code.meta().name()}{postal_code.meta().label()}{postal_code.meta().required()}</p>
+                <p>{form.at_str("address.zip").is_err()}</p>
+                <p>{Order::path().items_elem(3).price().to_string()}</p>
+                <p>{form.at_str("items[2].price").is_err()}</p>
+                <p>{form.at_str("items.price").is_err()}</p>
+            </>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(
+        render::<Test>().await,
+        "<p>Brussels</p><p>1000</p><p>42</p><p>20</p><p>42trueitems-1-price</p>\
+         <p>address.zipPostal codetrue</p><p>true</p><p>items[3].price</p><p>true</p><p>true</p>"
+    );
+}
+
+#[test]
+async fn test_visit_fields() {
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Order {
+        address: Address,
+        items: Vec<Item>,
+        range: (u32, u32),
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Address {
+        #[yfc(name = "town")]
+        city: String,
+    }
+
+    #[derive(Model, Debug, PartialEq, Eq)]
+    struct Item {
+        price: u32,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Order {
+            address: Address {
+                city: "Ghent".into(),
+            },
+            items: vec![Item { price: 1 }, Item { price: 2 }],
+            range: (0, 10),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.visit_fields_mut(&mut |_: &yfc::FieldPath, field: &mut yfc::field::Field| {
+                    field.set_touched(true)
+                });
+            }
+        })?;
+
+        let mut fields = vec![];
+        form.state()
+            .visit(&mut |path: &yfc::FieldPath, field: &yfc::field::Field| {
+                fields.push(format!("{}={}:{}", path, field.value(), field.touched()))
+            });
+
+        Ok(html! {
+            <p>{fields.join(",")}</p>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(
+        render::<Test>().await,
+        "<p>address.city=Ghent:true,items[0].price=1:true,items[1].price=2:true,\
+         range.0=0:true,range.1=10:true</p>"
+    );
+}
+
+#[test]
+async fn test_files() {
+    use yfc::files::{FileError, FileHandle, Files, MemoryFile};
+
+    #[derive(Model, Debug, PartialEq)]
+    struct Model {
+        attachments: Files,
+    }
+
+    let files = [
+        MemoryFile::new("photo.PNG", "image/png", vec![1, 2, 3]),
+        MemoryFile::new("notes.txt", "text/plain", vec![4; 10]),
+    ]
+    .into_iter()
+    .map(FileHandle::new)
+    .collect::<Files>();
+
+    assert_eq!(files.validate_accept("image/*, .txt"), Ok(()));
+    assert_eq!(files.validate_accept(""), Ok(()));
+    assert_eq!(
+        files.validate_accept(".png,application/pdf"),
+        Err(FileError::Type {
+            name: "notes.txt".into(),
+            mime_type: "text/plain".into()
+        })
+    );
+    assert_eq!(
+        files.validate_max_size(5),
+        Err(FileError::TooLarge {
+            name: "notes.txt".into(),
+            size: 10,
+            max: 5
+        })
+    );
+    assert_eq!(files.first().unwrap().bytes().await, Ok(vec![1, 2, 3]));
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model {
+            attachments: Files::default(),
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                let files = Files::new(vec![
+                    FileHandle::new(MemoryFile::new("a.pdf", "application/pdf", vec![])),
+                    FileHandle::new(MemoryFile::new("b.pdf", "application/pdf", vec![])),
+                ]);
+                form.attachments_form().state_mut().set_files(files);
+            }
+        })?;
+
+        let state = form.state();
+
+        Ok(html! {
+            <p>{&state.attachments}{":"}{state.attachments.dirty()}{":"}{form.model().attachments.len()}</p>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(render::<Test>().await, "<p>a.pdf, b.pdf:true:2</p>");
+}
+
+#[test]
+async fn test_mask() {
+    use yfc::mask::{Mask, MaskedInput, PatternMask};
+
+    let phone = PatternMask::new("(999) 999-9999");
+    assert_eq!(phone.format("5551234567"), "(555) 123-4567");
+    assert_eq!(phone.format("5551"), "(555) 1");
+    assert_eq!(phone.format("555"), "(555");
+    assert_eq!(phone.parse("(555) 123-4567"), "5551234567");
+    assert_eq!(phone.parse("555.123.4567 ext 89"), "5551234567");
+    assert_eq!(
+        phone.input("(555) 1x23", 8),
+        MaskedInput {
+            stored: "555123".into(),
+            display: "(555) 123".into(),
+            caret: 7,
+        }
+    );
+    assert_eq!(phone.input("(5550) 123", 5).caret, 7);
+
+    let prefixed = PatternMask::new("+1 999");
+    assert_eq!(prefixed.parse("+1 555"), "555");
+    assert_eq!(prefixed.format("555"), "+1 555");
+
+    let iban = PatternMask::new("AA99 9999");
+    assert_eq!(iban.parse("de44 5001"), "DE445001");
+    assert_eq!(iban.format("DE445001"), "DE44 5001");
+
+    #[derive(Model, Debug, PartialEq)]
+    struct Model {
+        #[yfc(mask = "(999) 999-9999")]
+        phone: String,
+        #[yfc(mask = PatternMask::new("99999"))]
+        zip: String,
+        name: String,
+    }
+
+    #[function_component(Component)]
+    fn component() -> Html {
+        let form = use_form(|| Model {
+            phone: "".into(),
+            zip: "".into(),
+            name: "".into(),
+        });
+        let format = |form: Form<String>| {
+            form.meta()
+                .mask()
+                .map(|mask| mask.format("123456"))
+                .unwrap_or_default()
+        };
+
+        html! {
+            <p>{format(form.phone_form())}{":"}{format(form.zip_form())}{":"}{format(form.name_form())}</p>
+        }
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(render::<Test>().await, "<p>(123) 456:12345:</p>");
+}
+
+#[test]
+async fn test_transform() {
+    use yfc::transform::Step;
+
+    fn collapse_spaces(value: &str) -> String {
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    let transform = Step::Trim | Step::Uppercase;
+    assert_eq!(transform.apply(" abc "), "ABC");
+    assert_eq!(Step::Nfc.apply("e\u{301}"), "\u{e9}");
+
+    #[derive(Model, Debug, PartialEq)]
+    struct Model {
+        #[yfc(transform = trim | lowercase)]
+        email: String,
+        #[yfc(transform_on_blur = trim | collapse_spaces)]
+        name: String,
+        #[yfc(transform_on_blur = trim)]
+        nickname: String,
+        #[yfc(transform = nfc)]
+        bio: String,
+        #[yfc(transform = uppercase)]
+        code: String,
+        #[yfc(transform = trim)]
+        alias: Option<String>,
+    }
+
+    #[function_component(Component)]
+    fn component() -> HtmlResult {
+        let form = use_form(|| Model {
+            email: "".into(),
+            name: "".into(),
+            nickname: "".into(),
+            bio: "".into(),
+            code: "".into(),
+            alias: None,
+        });
+
+        use_once({
+            let form = form.clone();
+            move || {
+                form.email_form().set("  Jane@Example.COM ");
+                form.name_form().set(" Jane   Doe ");
+                form.name_form().apply_transform();
+                form.nickname_form().set(" JD ");
+                form.bio_form().set("Cafe\u{301}");
+                // Setters other than `Form::set` apply the transform as well
+                form.state_mut().set_code("ab-1");
+                form.at_str("alias").unwrap().set(" jd ");
+            }
+        })?;
+
+        let state = form.state();
+        let model = form.model();
+
+        Ok(html! {
+            <p>
+                {&state.email}{":"}{&model.email}{":"}
+                {&state.name}{":"}{&model.name}{":"}{&state.nickname}{":"}
+                {model.bio == "Caf\u{e9}"}{":"}
+                {&state.code}{":"}{&model.code}{":"}{model.alias.as_deref().unwrap_or_default()}
+            </p>
+        })
+    }
+
+    create_test_comp!(Component);
+
+    assert_eq!(
+        render::<Test>().await,
+        "<p>jane@example.com:jane@example.com:Jane Doe:Jane Doe: JD :true:AB-1:AB-1:jd</p>"
+    );
+}
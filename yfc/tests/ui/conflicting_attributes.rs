@@ -0,0 +1,16 @@
+use yfc::prelude::*;
+
+#[derive(Model, PartialEq)]
+struct Model {
+    #[yfc(model, value)]
+    child: u32,
+    #[yfc(list)]
+    #[yfc(list)]
+    values: Vec<u32>,
+    #[yfc(skip, list)]
+    skipped: Vec<u32>,
+    #[yfc(skip, label = "Ignored")]
+    labelled: u32,
+}
+
+fn main() {}
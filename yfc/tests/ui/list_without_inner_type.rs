@@ -0,0 +1,11 @@
+use yfc::prelude::*;
+
+#[derive(Model, PartialEq)]
+struct Model {
+    #[yfc(list)]
+    values: String,
+    #[yfc(list)]
+    pair: (u32, u32),
+}
+
+fn main() {}
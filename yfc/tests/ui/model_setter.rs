@@ -0,0 +1,17 @@
+use yfc::prelude::*;
+
+#[derive(Model, PartialEq)]
+struct Parent {
+    child: Child,
+}
+
+#[derive(Model, PartialEq)]
+struct Child {
+    value: u32,
+}
+
+fn set(form: Form<Parent>) {
+    form.state_mut().set_child("42");
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use yfc::prelude::*;
+
+#[derive(Model, PartialEq)]
+struct Model {
+    #[yfc(modle)]
+    child: u32,
+    #[yfc(nested)]
+    other: u32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use yfc::prelude::*;
+
+#[derive(Model, PartialEq)]
+struct Unnamed(u32);
+
+#[derive(Model, PartialEq)]
+enum Enum {
+    A,
+}
+
+#[derive(Model, PartialEq)]
+struct Reference {
+    value: &'static str,
+}
+
+fn main() {}
@@ -73,3 +73,59 @@ async fn test_field_component() {
     let html = render::<Comp>().await;
     assert_eq!(html, "<p>1</p>");
 }
+
+#[test]
+async fn test_form_context() {
+    #[derive(Model, Debug, PartialEq)]
+    struct Model {
+        id: u32,
+        address: Address,
+    }
+
+    #[derive(Model, Debug, PartialEq)]
+    struct Address {
+        city: String,
+    }
+
+    #[function_component(IdComp)]
+    fn id_comp() -> Html {
+        let form = use_form_context::<Model>().unwrap();
+
+        html! {
+            <p>{&form.state().id}</p>
+        }
+    }
+
+    #[function_component(CityComp)]
+    fn city_comp() -> Html {
+        let form = use_form_context::<String>().unwrap();
+
+        html! {
+            <p>{form.meta().name()}{"="}{form.value().clone()}</p>
+        }
+    }
+
+    #[function_component(Comp)]
+    fn comp() -> Html {
+        let form = use_form(|| Model {
+            id: 1,
+            address: Address {
+                city: "Ghent".into(),
+            },
+        });
+
+        html! {
+            <yfc::FormProvider<Model> {form}>
+                <IdComp/>
+                <yfc::FormScope<ModelAddressRelation> relation={ModelAddressRelation}>
+                    <yfc::FormScope<AddressCityRelation> relation={AddressCityRelation}>
+                        <CityComp/>
+                    </yfc::FormScope<AddressCityRelation>>
+                </yfc::FormScope<ModelAddressRelation>>
+            </yfc::FormProvider<Model>>
+        }
+    }
+
+    let html = render::<Comp>().await;
+    assert_eq!(html, "<p>1</p><p>address.city=Ghent</p>");
+}
@@ -1,12 +1,9 @@
 use inflector::Inflector;
 use proc_macro::TokenStream;
-use proc_macro2::Span;
 use quote::{format_ident, quote};
 use syn::{
     parse::{Parse, ParseStream},
-    parse_macro_input,
-    spanned::Spanned,
-    DeriveInput, Error, GenericArgument, PathArguments, Result, Token,
+    parse_macro_input, DeriveInput, Error, GenericArgument, PathArguments, Result, Token,
 };
 
 #[rustfmt::skip::macros(quote)]
@@ -18,305 +15,556 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
         .into()
 }
 
-enum FieldAttr {
+const FIELD_ATTRS: &[&str] = &[
+    "model",
+    "value",
+    "list",
+    "skip",
+    "default",
+    "name",
+    "label",
+    "placeholder",
+    "help",
+    "mask",
+    "transform",
+    "transform_on_blur",
+    "required",
+];
+
+/// Pairs of attributes that can't be combined on the same field.
+const CONFLICTING_ATTRS: &[(&str, &str)] = &[
+    ("model", "value"),
+    ("skip", "model"),
+    ("skip", "value"),
+    ("skip", "list"),
+    // Skipped fields aren't rendered, so they have no metadata
+    ("skip", "name"),
+    ("skip", "label"),
+    ("skip", "placeholder"),
+    ("skip", "help"),
+    ("skip", "mask"),
+    ("skip", "transform"),
+    ("skip", "transform_on_blur"),
+    ("skip", "required"),
+    ("transform", "transform_on_blur"),
+];
+
+enum FieldAttrKind {
     Model,
     List,
     Value,
+    Skip,
+    Default(Box<syn::Expr>),
+    Name(syn::LitStr),
+    Label(syn::LitStr),
+    Placeholder(syn::LitStr),
+    Help(syn::LitStr),
+    Mask(Box<syn::Expr>),
+    Transform(Box<syn::Expr>, bool),
+    Required,
+}
+
+struct FieldAttr {
+    ident: syn::Ident,
+    kind: FieldAttrKind,
 }
 
 impl Parse for FieldAttr {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident: syn::Ident = input.parse()?;
-        if ident == "model" {
-            Ok(FieldAttr::Model)
-        } else if ident == "value" {
-            Ok(FieldAttr::Value)
-        } else if ident == "list" {
-            Ok(FieldAttr::List)
-        } else {
-            Err(input.error("Expected model, value or list"))
+        let kind = match ident.to_string().as_str() {
+            "model" => FieldAttrKind::Model,
+            "value" => FieldAttrKind::Value,
+            "list" => FieldAttrKind::List,
+            "skip" => FieldAttrKind::Skip,
+            "default" => {
+                input.parse::<Token![=]>()?;
+                FieldAttrKind::Default(Box::new(input.parse()?))
+            }
+            "name" => FieldAttrKind::Name(parse_attr_value(input)?),
+            "label" => FieldAttrKind::Label(parse_attr_value(input)?),
+            "placeholder" => FieldAttrKind::Placeholder(parse_attr_value(input)?),
+            "help" => FieldAttrKind::Help(parse_attr_value(input)?),
+            "mask" => FieldAttrKind::Mask(Box::new(parse_attr_value(input)?)),
+            "transform" => FieldAttrKind::Transform(Box::new(parse_attr_value(input)?), false),
+            "transform_on_blur" => {
+                FieldAttrKind::Transform(Box::new(parse_attr_value(input)?), true)
+            }
+            "required" => FieldAttrKind::Required,
+            _ => return Err(unknown_attr(&ident, FIELD_ATTRS)),
+        };
+
+        Ok(FieldAttr { ident, kind })
+    }
+}
+
+/// Parses the `= value` part of an attribute.
+fn parse_attr_value<T: Parse>(input: ParseStream) -> Result<T> {
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
+/// Creates the error for an unknown attribute, suggesting the closest known one if any.
+fn unknown_attr(ident: &syn::Ident, known: &[&str]) -> Error {
+    let name = ident.to_string();
+    let suggestion = known
+        .iter()
+        .map(|attr| (edit_distance(&name, attr), attr))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance);
+
+    match suggestion {
+        Some((_, attr)) => Error::new(
+            ident.span(),
+            format!("Unknown attribute `{}`, did you mean `{}`?", name, attr),
+        ),
+        None => Error::new(
+            ident.span(),
+            format!(
+                "Unknown attribute `{}`, expected one of: {}",
+                name,
+                known.join(", ")
+            ),
+        ),
+    }
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
         }
     }
+
+    row[b.len()]
 }
 
-fn expand_model(ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
-    let fields: Vec<syn::Field> = match ast.data {
-        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => {
-            if fields.iter().any(|field| field.ident.is_none()) {
-                return Err(syn::Error::new(
-                    Span::call_site(),
-                    "Model can't have unnamed fields",
+/// Folds a list of results into a single result, combining all the errors.
+fn combine_errors<T>(results: impl IntoIterator<Item = Result<T>>) -> Result<Vec<T>> {
+    let mut values = vec![];
+    let mut error: Option<Error> = None;
+
+    for result in results {
+        match (result, &mut error) {
+            (Ok(value), _) => values.push(value),
+            (Err(e), Some(error)) => error.combine(e),
+            (Err(e), None) => error = Some(e),
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(values),
+    }
+}
+
+/// The steps of a `transform = trim | lowercase | path::to_fn` attribute.
+fn transform_steps(expr: &syn::Expr, steps: &mut Vec<proc_macro2::TokenStream>) -> Result<()> {
+    match expr {
+        syn::Expr::Binary(syn::ExprBinary {
+            left,
+            op: syn::BinOp::BitOr(_),
+            right,
+            ..
+        }) => {
+            transform_steps(left, steps)?;
+            transform_steps(right, steps)
+        }
+        syn::Expr::Path(path) => {
+            let step = match path.path.get_ident().map(|ident| ident.to_string()).as_deref() {
+                Some("trim") => quote!(yfc::transform::Step::Trim),
+                Some("lowercase") => quote!(yfc::transform::Step::Lowercase),
+                Some("uppercase") => quote!(yfc::transform::Step::Uppercase),
+                Some("nfc") => quote!(yfc::transform::Step::Nfc),
+                _ => quote!(yfc::transform::Step::Fn(#path)),
+            };
+            steps.push(step);
+            Ok(())
+        }
+        expr => Err(Error::new_spanned(
+            expr,
+            "Expected `trim`, `lowercase`, `uppercase`, `nfc` or the path of a function, combined with `|`",
+        )),
+    }
+}
+
+/// The `Transform` of a `transform` or `transform_on_blur` attribute.
+fn expand_transform(expr: &syn::Expr, on_blur: bool) -> Result<proc_macro2::TokenStream> {
+    let mut steps = vec![];
+    transform_steps(expr, &mut steps)?;
+    let on_blur = on_blur.then_some(quote!(.on_blur()));
+
+    Ok(quote!(
+        yfc::transform::Transform::new()
+            #(.then(#steps))*
+            #on_blur
+    ))
+}
+
+/// How the `set_*` functions of a field are generated.
+#[derive(Clone, Copy, PartialEq)]
+enum Setter {
+    /// `#[yfc(model)]`, the field gets no setter.
+    None,
+    /// `#[yfc(value)]`, the setter requires the field to be a `FormValue`.
+    Value,
+    /// No annotation, the setter only becomes callable when the field is a `FormValue`.
+    Auto,
+}
+
+enum FieldKind<'a> {
+    Single,
+    List(&'a syn::Type),
+    Tuple(Vec<&'a syn::Type>),
+}
+
+struct ModelField<'a> {
+    ident: syn::Ident,
+    ty: &'a syn::Type,
+    kind: FieldKind<'a>,
+    setter: Setter,
+    skip: bool,
+    default: Option<syn::Expr>,
+    name: String,
+    label: Option<syn::LitStr>,
+    placeholder: Option<syn::LitStr>,
+    help: Option<syn::LitStr>,
+    mask: Option<syn::Expr>,
+    transform: Option<proc_macro2::TokenStream>,
+    transform_on_blur: bool,
+    required: bool,
+}
+
+impl<'a> ModelField<'a> {
+    fn parse(field: &'a syn::Field) -> Result<Self> {
+        let ident = field.ident.clone().unwrap();
+        let mut setter = Setter::Auto;
+        let mut list_attr: Option<syn::Ident> = None;
+        let mut skip = false;
+        let mut default = None;
+        let mut name = ident.to_string();
+        let mut label = None;
+        let mut placeholder = None;
+        let mut help = None;
+        let mut mask = None;
+        let mut transform = None;
+        let mut transform_on_blur = false;
+        let mut required = false;
+        let mut seen: Vec<syn::Ident> = vec![];
+
+        let attributes = combine_errors(
+            field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("yfc"))
+                .map(|attr| {
+                    attr.parse_args_with(|input: ParseStream<'_>| {
+                        input.parse_terminated::<FieldAttr, Token![,]>(FieldAttr::parse)
+                    })
+                }),
+        )?;
+
+        for attr in attributes.into_iter().flatten() {
+            if let Some(previous) = seen.iter().find(|previous| **previous == attr.ident) {
+                return Err(Error::new(
+                    attr.ident.span(),
+                    format!("Duplicate attribute `{}`", previous),
                 ));
             }
 
-            fields.iter().cloned().collect()
-        }
-        _ => {
-            return Err(syn::Error::new(
-                Span::call_site(),
-                "#[derive(Model)] can only be used with struct",
-            ))
+            if let Some(previous) = seen.iter().find(|previous| {
+                CONFLICTING_ATTRS.iter().any(|(a, b)| {
+                    (*previous == a && attr.ident == b) || (*previous == b && attr.ident == a)
+                })
+            }) {
+                return Err(Error::new(
+                    attr.ident.span(),
+                    format!("`{}` conflicts with `{}`", attr.ident, previous),
+                ));
+            }
+
+            match attr.kind {
+                FieldAttrKind::Model => setter = Setter::None,
+                FieldAttrKind::Value => setter = Setter::Value,
+                FieldAttrKind::List => list_attr = Some(attr.ident.clone()),
+                FieldAttrKind::Skip => skip = true,
+                FieldAttrKind::Default(expr) => default = Some(*expr),
+                FieldAttrKind::Name(value) => name = value.value(),
+                FieldAttrKind::Label(value) => label = Some(value),
+                FieldAttrKind::Placeholder(value) => placeholder = Some(value),
+                FieldAttrKind::Help(value) => help = Some(value),
+                FieldAttrKind::Mask(expr) => mask = Some(*expr),
+                FieldAttrKind::Transform(expr, on_blur) => {
+                    transform = Some(expand_transform(&expr, on_blur)?);
+                    transform_on_blur = on_blur;
+                }
+                FieldAttrKind::Required => required = true,
+            }
+
+            seen.push(attr.ident);
         }
-    };
 
-    let mut value_names = vec![];
-    let mut value_types = vec![];
-    let mut value_idents = vec![];
-    let mut value_idents_setter = vec![];
-    let mut value_forms = vec![];
-
-    let mut value_list_names = vec![];
-    let mut value_list_types = vec![];
-    let mut value_list_inner_types = vec![];
-    let mut value_list_idents = vec![];
-    let mut value_list_idents_setter = vec![];
-    let mut value_list_forms = vec![];
-    let mut value_list_elem_forms = vec![];
-
-    let mut model_names = vec![];
-    let mut model_types = vec![];
-    let mut model_idents = vec![];
-    let mut model_forms = vec![];
-
-    let mut model_list_names = vec![];
-    let mut model_list_types = vec![];
-    let mut model_list_inner_types = vec![];
-    let mut model_list_idents = vec![];
-    let mut model_list_forms = vec![];
-    let mut model_list_elem_forms = vec![];
+        // Skipped fields don't take part in the form, so their type doesn't matter
+        if skip {
+            return Ok(Self {
+                ident,
+                ty: &field.ty,
+                kind: FieldKind::Single,
+                setter: Setter::None,
+                skip,
+                default,
+                name,
+                label,
+                placeholder,
+                help,
+                mask,
+                transform,
+                transform_on_blur,
+                required,
+            });
+        }
 
-    let struct_name = &ast.ident;
-    let form_ext = format_ident!("{}FormExt", struct_name);
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
-    let visibility = ast.vis;
+        let kind = match &field.ty {
+            syn::Type::Path(path) => {
+                let last = path.path.segments.last();
+                let inner_ty = last.and_then(|last| match &last.arguments {
+                    PathArguments::AngleBracketed(generics) => match generics.args.first() {
+                        Some(GenericArgument::Type(inner_ty)) => Some(inner_ty),
+                        _ => None,
+                    },
+                    _ => None,
+                });
 
-    for field in &fields {
-        let field_ident = field.ident.clone().unwrap();
-        let (field_type, field_inner_type) = match &field.ty {
-            outer_ty @ syn::Type::Path(ref path) => {
-                if let Some(last) = path.path.segments.last() {
-                    if let PathArguments::AngleBracketed(generics) = &last.arguments {
-                        if let Some(GenericArgument::Type(inner_ty)) = generics.args.first() {
-                            (outer_ty, Some(inner_ty))
-                        } else {
-                            (outer_ty, None)
-                        }
-                    } else {
-                        (outer_ty, None)
-                    }
+                // `Vec`s are always lists, other generic types only when marked with `#[yfc(list)]`
+                if list_attr.is_some() || last.is_some_and(|last| last.ident == "Vec") {
+                    let Some(inner_ty) = inner_ty else {
+                        return Err(Error::new_spanned(
+                            &field.ty,
+                            format!(
+                                "A list requires an inner type, `{}` has no type argument",
+                                quote!(#path)
+                            ),
+                        ));
+                    };
+                    FieldKind::List(inner_ty)
                 } else {
-                    (outer_ty, None)
+                    FieldKind::Single
                 }
             }
-            outer_ty @ _ => {
-                return Err(Error::new(
-                    field.span(),
+            syn::Type::Array(array) => FieldKind::List(&array.elem),
+            syn::Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+                if let Some(list_attr) = list_attr {
+                    return Err(Error::new(
+                        list_attr.span(),
+                        "A tuple can't be a list, use an array or `Vec` instead",
+                    ));
+                }
+                FieldKind::Tuple(tuple.elems.iter().collect())
+            }
+            ty => {
+                return Err(Error::new_spanned(
+                    ty,
                     format!(
-                        "Type `{:?}` of field `{:?}` is not supported",
-                        outer_ty, field_ident
+                        "Type `{}` of field `{}` is not supported",
+                        quote!(#ty),
+                        ident
                     ),
                 ));
             }
         };
 
-        let field_name = field_ident.to_string();
-        let mut is_model = false;
-        let mut is_list = false;
+        Ok(Self {
+            ident,
+            ty: &field.ty,
+            kind,
+            setter,
+            skip,
+            default,
+            name,
+            label,
+            placeholder,
+            help,
+            mask,
+            transform,
+            transform_on_blur,
+            required,
+        })
+    }
 
-        if let Some(attr) = field.attrs.iter().find(|attr| attr.path.is_ident("yfc")) {
-            let attributes = attr
-                .parse_args_with(|input: ParseStream<'_>| {
-                    input.parse_terminated::<FieldAttr, Token![,]>(FieldAttr::parse)
-                })
-                .unwrap();
+    /// The segment of this field in a `FieldPath`, its identifier regardless of the `name`.
+    fn path_name(&self) -> String {
+        self.ident.to_string()
+    }
 
-            for attr in attributes.iter() {
-                match attr {
-                    FieldAttr::Model => is_model = true,
-                    FieldAttr::List => is_list = true,
-                    FieldAttr::Value => is_model = false,
-                }
-            }
-        }
+    /// The `FieldMeta` of this field, or one of its elements when given a different `name`.
+    fn meta(&self, name: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let label = self.label.iter();
+        let placeholder = self.placeholder.iter();
+        let help = self.help.iter();
+        // A string is a pattern, any other expression a `Mask`
+        let mask = self.mask.iter().map(|mask| match mask {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(pattern),
+                ..
+            }) => quote!(yfc::mask::PatternMask::new(#pattern)),
+            mask => quote!(#mask),
+        });
+        let transform = self.transform.iter();
+        let required = self.required.then_some(quote!(.with_required(true)));
+
+        quote!(
+            yfc::meta::FieldMeta::new(#name)
+                #(.with_label(#label))*
+                #(.with_placeholder(#placeholder))*
+                #(.with_help(#help))*
+                #(.with_mask(#mask))*
+                #(.with_transform(#transform))*
+                #required
+        )
+    }
+
+    /// The `input_transform` of the relations of this field, left to the default `None` unless
+    /// the field has a transform applied on set.
+    fn input_transform(&self) -> Option<proc_macro2::TokenStream> {
+        let transform = self
+            .transform
+            .as_ref()
+            .filter(|_| !self.transform_on_blur)?;
 
-        match (is_model, is_list) {
-            (true, true) => {
-                let Some(field_inner_type) =  field_inner_type else{
-                    panic!("A list requires an inner type");
-                };
-                model_list_names.push(field_name);
-                model_list_types.push(field_type);
-                model_list_inner_types.push(field_inner_type);
-                model_list_forms.push(format_ident!("{}_form", field_ident));
-                model_list_elem_forms.push(format_ident!("{}_elem_form", field_ident));
-                model_list_idents.push(field_ident);
-            }
-            (true, false) => {
-                model_names.push(field_name);
-                model_types.push(field_type);
-                model_forms.push(format_ident!("{}_form", field_ident));
-                model_idents.push(field_ident);
-            }
-            (false, true) => {
-                let Some(field_inner_type) =  field_inner_type else{
-                    panic!("A list requires an inner type");
-                };
-
-                value_list_names.push(field_name);
-                value_list_types.push(field_type);
-                value_list_inner_types.push(field_inner_type);
-                value_list_idents_setter.push(format_ident!("set_{}", field_ident));
-                value_list_forms.push(format_ident!("{}_form", field_ident));
-                value_list_elem_forms.push(format_ident!("{}_elem_form", field_ident));
-                value_list_idents.push(field_ident);
-            }
-            (false, false) => {
-                value_names.push(field_name);
-                value_types.push(field_type);
-                value_forms.push(format_ident!("{}_form", field_ident));
-                value_idents_setter.push(format_ident!("set_{}", field_ident));
-                value_idents.push(field_ident);
+        Some(quote!(
+            fn input_transform(&self) -> ::std::option::Option<yfc::transform::Transform> {
+                ::std::option::Option::Some(#transform)
             }
+        ))
+    }
+
+    /// The generic bound added to the setter of a value of type `ty`.
+    ///
+    /// Unannotated fields get a setter that is bound on the type being a `FormValue`, the higher
+    /// ranked lifetime defers checking the bound until the setter is called. This way nested
+    /// models can go without `#[yfc(model)]` and only complain when their setter is used.
+    fn setter_bound(&self, ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+        match self.setter {
+            Setter::None => None,
+            Setter::Value => Some(quote!()),
+            Setter::Auto => Some(quote!(where for<'yfc> #ty: yfc::form_value::FormValue)),
         }
     }
+}
 
-    let (state_struct_name, state_struct_impl) = expand_state_struct(
-        &visibility,
-        &struct_name,
-        &value_idents,
-        &value_types,
-        &value_list_idents,
-        &value_list_types,
-        &value_list_inner_types,
-        &model_idents,
-        &model_types,
-        &model_list_idents,
-        &model_list_types,
-        &model_list_inner_types,
-    );
+fn expand_model(ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(ref fields),
+            ..
+        }) => combine_errors(fields.named.iter().map(ModelField::parse))?,
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unit,
+            ..
+        }) => vec![],
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "Model can't have unnamed fields",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "#[derive(Model)] can only be used with struct",
+            ))
+        }
+    };
 
-    let (value_relations, value_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &value_types,
-        &value_idents,
-        false,
-    );
+    let (skipped, fields): (Vec<_>, Vec<_>) = fields.into_iter().partition(|f| f.skip);
 
-    let (value_list_relations, value_list_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &value_list_types,
-        &value_list_idents,
-        false,
-    );
+    let struct_name = &ast.ident;
+    let state_struct_name = format_ident!("{}State", struct_name);
+    let form_ext = format_ident!("{}FormExt", struct_name);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let visibility = ast.vis;
 
-    let (value_list_elem_relations, value_list_elem_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &value_list_inner_types,
-        &value_list_idents,
-        true,
-    );
+    let idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
 
-    let (model_relations, model_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &model_types,
-        &model_idents,
-        false,
-    );
+    let state_struct_impl = expand_state_struct(&visibility, &state_struct_name, &fields);
+    let (state_mut_struct_name, state_mut_struct_impl) =
+        expand_state_mut_struct(&visibility, struct_name, &state_struct_name, &fields);
+    let relation_impls = expand_relations(&visibility, struct_name, &state_struct_name, &fields);
+    let model_default_impl = expand_model_default(struct_name, &ast.generics, &fields, &skipped);
+    let field_lookup_impl =
+        expand_field_lookup(struct_name, &state_struct_name, &ast.generics, &fields);
+    let path_impl = expand_path(&visibility, struct_name, &fields);
 
-    let (model_list_relations, model_list_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &model_list_types,
-        &model_list_idents,
-        false,
-    );
+    let mut form_fns = vec![];
+    let mut form_fn_impls = vec![];
 
-    let (model_list_elem_relations, model_list_elem_relation_impls) = expand_relations(
-        &struct_name,
-        &state_struct_name,
-        &model_list_inner_types,
-        &model_list_idents,
-        true,
-    );
+    for field in &fields {
+        let ident = &field.ident;
+        let ty = field.ty;
+        let form = format_ident!("{}_form", ident);
+        let relation = relation_name(struct_name, ident);
 
-    let (state_mut_struct_name, state_mut_struct_impl) = expand_state_mut_struct(
-        &visibility,
-        &struct_name,
-        &state_struct_name,
-        &value_idents,
-        &value_types,
-        &value_relations,
-        &value_list_idents,
-        &value_list_types,
-        &value_list_relations,
-        &value_list_elem_relations,
-        &model_idents,
-        &model_types,
-        &model_relations,
-        &model_list_idents,
-        &model_list_types,
-        &model_list_relations,
-    );
+        form_fns.push(quote!(fn #form(&self) -> yfc::form::Form<#ty>;));
+        form_fn_impls.push(quote!(
+            fn #form(&self) -> yfc::form::Form<#ty> {
+                self.seed(#relation)
+            }
+        ));
+
+        match &field.kind {
+            FieldKind::Single => {}
+            FieldKind::List(inner_ty) => {
+                let elem_form = format_ident!("{}_elem_form", ident);
+                let elem_relation = elem_relation_name(struct_name, ident);
+
+                form_fns.push(quote!(
+                    fn #elem_form(&self, index: usize) -> yfc::form::Form<#inner_ty>;
+                ));
+                form_fn_impls.push(quote!(
+                    fn #elem_form(&self, index: usize) -> yfc::form::Form<#inner_ty> {
+                        self.seed(#elem_relation(index))
+                    }
+                ));
+            }
+            FieldKind::Tuple(elem_types) => {
+                for (index, elem_ty) in elem_types.iter().enumerate() {
+                    let elem_form = format_ident!("{}_{}_form", ident, index);
+                    let elem_relation = tuple_relation_name(struct_name, ident, index);
+
+                    form_fns.push(quote!(fn #elem_form(&self) -> yfc::form::Form<#elem_ty>;));
+                    form_fn_impls.push(quote!(
+                        fn #elem_form(&self) -> yfc::form::Form<#elem_ty> {
+                            self.seed(#elem_relation)
+                        }
+                    ));
+                }
+            }
+        }
+    }
 
     Ok(quote!(
         #state_struct_impl
         #state_mut_struct_impl
-
-        #value_relation_impls
-        #value_list_relation_impls
-        #value_list_elem_relation_impls
-        #model_relation_impls
-        #model_list_relation_impls
-        #model_list_elem_relation_impls
+        #relation_impls
+        #model_default_impl
+        #field_lookup_impl
+        #path_impl
 
         #visibility trait #form_ext {
-            #(
-                fn #value_forms(&self) -> yfc::form::Form<#value_types>;
-            )*
-            #(
-                fn #value_list_elem_forms(&self, index: usize) -> yfc::form::Form<#value_list_inner_types>;
-                fn #value_list_forms(&self) -> yfc::form::Form<#value_list_types>;
-            )*
-            #(
-                fn #model_forms(&self) -> yfc::form::Form<#model_types>;
-            )*
-            #(
-                fn #model_list_elem_forms(&self, index: usize) -> yfc::form::Form<#model_list_inner_types>;
-                fn #model_list_forms(&self) -> yfc::form::Form<#model_list_types>;
-            )*
+            #(#form_fns)*
         }
 
         impl #form_ext for yfc::form::Form<#struct_name> {
-            #(
-                fn #value_forms(&self) -> yfc::form::Form<#value_types> {
-                    self.seed(#value_relations)
-                }
-            )*
-            #(
-                fn #value_list_elem_forms(&self, index: usize) -> yfc::form::Form<#value_list_inner_types> {
-                    self.seed(#value_list_elem_relations(index))
-                }
-                fn #value_list_forms(&self) -> yfc::form::Form<#value_list_types> {
-                    self.seed(#value_list_relations)
-                }
-            )*
-            #(
-                fn #model_forms(&self) -> yfc::form::Form<#model_types> {
-                    self.seed(#model_relations)
-                }
-            )*
-            #(
-                fn #model_list_elem_forms(&self, index: usize) -> yfc::form::Form<#model_list_inner_types> {
-                    self.seed(#model_list_elem_relations(index))
-                }
-                fn #model_list_forms(&self) -> yfc::form::Form<#model_list_types> {
-                    self.seed(#model_list_relations)
-                }
-            )*
+            #(#form_fn_impls)*
         }
 
         impl #impl_generics yfc::form_state::StateProvider for #struct_name #ty_generics #where_clause {
@@ -326,16 +574,7 @@ fn expand_model(ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
             fn create_state(&self) -> Self::State {
                 Self::State {
                     #(
-                        #value_idents: self.#value_idents.create_state(),
-                    )*
-                    #(
-                        #value_list_idents: self.#value_list_idents.create_state(),
-                    )*
-                    #(
-                        #model_idents: self.#model_idents.create_state(),
-                    )*
-                    #(
-                        #model_list_idents: self.#model_list_idents.create_state(),
+                        #idents: self.#idents.create_state(),
                     )*
                 }
             }
@@ -351,45 +590,293 @@ fn expand_model(ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
     ))
 }
 
-fn expand_state_struct(
-    visibility: &syn::Visibility,
+fn expand_model_default(
     struct_name: &syn::Ident,
-    value_idents: &[syn::Ident],
-    value_types: &[&syn::Type],
-    value_list_idents: &[syn::Ident],
-    value_list_types: &[&syn::Type],
-    value_list_inner_types: &[&syn::Type],
-    model_idents: &[syn::Ident],
-    model_types: &[&syn::Type],
-    model_list_idents: &[syn::Ident],
-    model_list_types: &[&syn::Type],
-    model_list_inner_types: &[&syn::Type],
-) -> (syn::Ident, proc_macro2::TokenStream) {
-    let state_struct_name = format_ident!("{}State", struct_name);
+    generics: &syn::Generics,
+    fields: &[ModelField],
+    skipped: &[ModelField],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut predicates = where_clause
+        .map(|w| w.predicates.iter().map(|p| quote!(#p)).collect())
+        .unwrap_or_else(Vec::new);
+    let mut reset_predicates = predicates.clone();
+    let mut values = vec![];
+    let mut resets = vec![];
 
-    let idents = value_idents
-        .iter()
-        .chain(model_idents)
-        .chain(value_list_idents)
-        .chain(model_list_idents)
-        .collect::<Vec<_>>();
+    // Like the setters the bounds are deferred, so models with fields lacking a default only fail
+    // when they are actually reset.
+    for field in fields.iter().chain(skipped) {
+        let ident = &field.ident;
+        let ty = field.ty;
 
-    let types = value_types
+        let value = match (&field.default, field.skip) {
+            (Some(default), _) => quote!(#default),
+            (None, false) => {
+                predicates.push(quote!(for<'yfc> #ty: yfc::model::ModelDefault));
+                quote!(yfc::model::ModelDefault::model_default())
+            }
+            (None, true) => {
+                predicates.push(quote!(for<'yfc> #ty: ::std::default::Default));
+                quote!(::std::default::Default::default())
+            }
+        };
+
+        // Skipped fields aren't form data, so resetting keeps them and needs no default for them
+        match (&field.default, field.skip) {
+            (_, true) => {}
+            (Some(default), false) => resets.push(quote!(self.#ident = #default;)),
+            (None, false) => {
+                reset_predicates.push(quote!(for<'yfc> #ty: yfc::model::ModelReset));
+                resets.push(quote!(yfc::model::ModelReset::reset_model(&mut self.#ident);))
+            }
+        }
+
+        values.push(quote!(#ident: #value));
+    }
+
+    quote!(
+        impl #impl_generics yfc::model::ModelDefault for #struct_name #ty_generics
+        where
+            #(#predicates,)*
+        {
+            fn model_default() -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+
+        impl #impl_generics yfc::model::ModelReset for #struct_name #ty_generics
+        where
+            #(#reset_predicates,)*
+        {
+            fn reset_model(&mut self) {
+                #(#resets)*
+            }
+        }
+    )
+}
+
+fn expand_field_lookup(
+    struct_name: &syn::Ident,
+    state_struct_name: &syn::Ident,
+    generics: &syn::Generics,
+    fields: &[ModelField],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut predicates = where_clause
+        .map(|w| w.predicates.iter().map(|p| quote!(#p)).collect())
+        .unwrap_or_else(Vec::new);
+    let idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let path_names = fields.iter().map(|f| f.path_name()).collect::<Vec<_>>();
+    let metas = fields
         .iter()
-        .chain(model_types)
-        .chain(value_list_types)
-        .chain(model_list_types)
+        .map(|f| {
+            let name = &f.name;
+            f.meta(quote!(#name))
+        })
         .collect::<Vec<_>>();
+    let types = fields.iter().map(|f| f.ty).collect::<Vec<_>>();
+
+    // Deferred as well, so fields with a custom `StateProvider` only fail when looked up.
+    for ty in &types {
+        predicates.push(quote!(for<'yfc> #ty: yfc::dyn_field::FieldLookup));
+    }
+
+    quote!(
+        impl #impl_generics yfc::dyn_field::FieldLookup for #struct_name #ty_generics
+        where
+            #(#predicates,)*
+        {
+            fn lookup<'a>(
+                state: &'a #state_struct_name,
+                path: &[yfc::path::PathSegment],
+            ) -> ::std::option::Option<&'a yfc::field::Field> {
+                match path.split_first()? {
+                    (yfc::path::PathSegment::Field(name), rest) => match &**name {
+                        #(
+                            #path_names => <#types as yfc::dyn_field::FieldLookup>::lookup(&state.#idents, rest),
+                        )*
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+
+            fn lookup_meta(
+                path: &[yfc::path::PathSegment],
+                meta: yfc::meta::FieldMeta,
+            ) -> ::std::option::Option<yfc::meta::FieldMeta> {
+                match path.split_first()? {
+                    (yfc::path::PathSegment::Field(name), rest) => match &**name {
+                        #(
+                            #path_names => <#types as yfc::dyn_field::FieldLookup>::lookup_meta(rest, #metas.nested_in(&meta)),
+                        )*
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+
+            fn lookup_mut<'a>(
+                model: &'a mut Self,
+                state: &'a mut #state_struct_name,
+                path: &[yfc::path::PathSegment],
+            ) -> ::std::option::Option<::std::boxed::Box<dyn yfc::dyn_field::DynFieldMut + 'a>> {
+                match path.split_first()? {
+                    (yfc::path::PathSegment::Field(name), rest) => match &**name {
+                        #(
+                            #path_names => <#types as yfc::dyn_field::FieldLookup>::lookup_mut(&mut model.#idents, &mut state.#idents, rest),
+                        )*
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+        }
+    )
+}
+
+/// The typed path of the model, named `{Struct}Path`.
+///
+/// Every field gets a function returning the typed path of its own type, lists also get
+/// `{field}_elem(index)` and tuples `{field}_{index}`.
+fn expand_path(
+    visibility: &syn::Visibility,
+    struct_name: &syn::Ident,
+    fields: &[ModelField],
+) -> proc_macro2::TokenStream {
+    let path_struct_name = format_ident!("{}Path", struct_name);
+    let mut fns = vec![];
+
+    let typed_path = |ty: &syn::Type| {
+        (
+            quote!(<#ty as yfc::path::HasPath>::Path),
+            quote!(where for<'yfc> #ty: yfc::path::HasPath),
+        )
+    };
+
+    for field in fields {
+        let ident = &field.ident;
+        let name = field.path_name();
+
+        match &field.kind {
+            FieldKind::Single => {
+                let (path, bound) = typed_path(field.ty);
+
+                fns.push(quote!(
+                    #visibility fn #ident(self) -> #path #bound {
+                        self.0.field(#name).into()
+                    }
+                ));
+            }
+            FieldKind::List(inner_ty) => {
+                let elem_ident = format_ident!("{}_elem", ident);
+                let (path, bound) = typed_path(inner_ty);
+
+                fns.push(quote!(
+                    #visibility fn #ident(self) -> yfc::path::FieldPath {
+                        self.0.field(#name)
+                    }
+
+                    #visibility fn #elem_ident(self, index: usize) -> #path #bound {
+                        self.0.field(#name).index(index).into()
+                    }
+                ));
+            }
+            FieldKind::Tuple(elem_types) => {
+                fns.push(quote!(
+                    #visibility fn #ident(self) -> yfc::path::FieldPath {
+                        self.0.field(#name)
+                    }
+                ));
+
+                for (index, elem_ty) in elem_types.iter().enumerate() {
+                    let elem_ident = format_ident!("{}_{}", ident, index);
+                    let elem_name = index.to_string();
+                    let (path, bound) = typed_path(elem_ty);
+
+                    fns.push(quote!(
+                        #visibility fn #elem_ident(self) -> #path #bound {
+                            self.0.field(#name).field(#elem_name).into()
+                        }
+                    ));
+                }
+            }
+        }
+    }
+
+    quote!(
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #visibility struct #path_struct_name(yfc::path::FieldPath);
+
+        impl #path_struct_name {
+            #(#fns)*
+        }
+
+        impl ::std::convert::From<yfc::path::FieldPath> for #path_struct_name {
+            fn from(path: yfc::path::FieldPath) -> Self {
+                Self(path)
+            }
+        }
+
+        impl ::std::convert::From<#path_struct_name> for yfc::path::FieldPath {
+            fn from(path: #path_struct_name) -> Self {
+                path.0
+            }
+        }
+
+        impl yfc::path::HasPath for #struct_name {
+            type Path = #path_struct_name;
+        }
+    )
+}
 
-    let list_inner_idents = value_list_idents.iter().chain(model_list_idents);
-    let list_inner_types = value_list_inner_types.iter().chain(model_list_inner_types);
+fn expand_state_struct(
+    visibility: &syn::Visibility,
+    state_struct_name: &syn::Ident,
+    fields: &[ModelField],
+) -> proc_macro2::TokenStream {
+    let idents = fields.iter().map(|f| &f.ident);
+    let types = fields.iter().map(|f| f.ty);
+
+    let dirty = fields.iter().map(|f| {
+        let ident = &f.ident;
+        match &f.kind {
+            FieldKind::Single => quote!(|| self.#ident.dirty()),
+            FieldKind::List(_) => quote!(|| self.#ident.iter().any(|x| x.dirty())),
+            FieldKind::Tuple(elem_types) => {
+                let indices = (0..elem_types.len()).map(syn::Index::from);
+                quote!(#(|| self.#ident.#indices.dirty())*)
+            }
+        }
+    });
+
+    let (list_inner_idents, list_inner_types): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter_map(|f| match f.kind {
+            FieldKind::List(inner_ty) => Some((&f.ident, inner_ty)),
+            _ => None,
+        })
+        .unzip();
 
     #[cfg(feature = "serde")]
     let derive_serde = quote!(#[derive(serde::Serialize, serde::Deserialize)]);
     #[cfg(not(feature = "serde"))]
     let derive_serde = quote!();
 
-    let state_struct = quote!(
+    let path_names = fields.iter().map(|f| f.path_name()).collect::<Vec<_>>();
+    let state_types = fields
+        .iter()
+        .map(|f| {
+            let ty = f.ty;
+            quote!(<#ty as yfc::form_state::StateProvider>::State)
+        })
+        .collect::<Vec<_>>();
+    let field_idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+
+    quote!(
         #derive_serde
         #[derive(Default, Debug, Clone, PartialEq, Eq)]
         #visibility struct #state_struct_name {
@@ -401,19 +888,7 @@ fn expand_state_struct(
         impl #state_struct_name {
             #visibility fn dirty(&self) -> bool {
                 false
-                #(
-                    || self.#value_idents.dirty()
-                )*
-                #(
-                    || self.#value_list_idents.iter().any(|x| x.dirty())
-                )*
-                #(
-                    || self.#model_idents.dirty()
-                )*
-                #(
-                    || self.#model_list_idents.iter().any(|x| x.dirty())
-                )*
-
+                #(#dirty)*
             }
 
             #(
@@ -422,48 +897,93 @@ fn expand_state_struct(
                 }
             )*
         }
-    );
 
-    (state_struct_name, state_struct)
+        impl yfc::visit::Visit for #state_struct_name
+        where
+            #(for<'yfc> #state_types: yfc::visit::Visit,)*
+        {
+            #[allow(unused_variables)]
+            fn visit_at<V: yfc::visit::FieldVisitor>(&self, path: &mut yfc::path::FieldPath, visitor: &mut V) {
+                #(
+                    yfc::visit::visit_segment(&self.#field_idents, yfc::path::PathSegment::Field(#path_names.into()), path, visitor);
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn visit_mut_at<V: yfc::visit::FieldVisitorMut>(&mut self, path: &mut yfc::path::FieldPath, visitor: &mut V) {
+                #(
+                    yfc::visit::visit_segment_mut(&mut self.#field_idents, yfc::path::PathSegment::Field(#path_names.into()), path, visitor);
+                )*
+            }
+        }
+    )
 }
 
 fn expand_state_mut_struct(
     visibility: &syn::Visibility,
     struct_name: &syn::Ident,
     state_struct_name: &syn::Ident,
-    value_idents: &[syn::Ident],
-    value_types: &[&syn::Type],
-    value_relations: &[syn::Ident],
-    value_list_idents: &[syn::Ident],
-    value_list_types: &[&syn::Type],
-    value_list_relations: &[syn::Ident],
-    value_list_elem_relations: &[syn::Ident],
-    model_idents: &[syn::Ident],
-    model_types: &[&syn::Type],
-    model_relations: &[syn::Ident],
-    model_list_idents: &[syn::Ident],
-    model_list_types: &[&syn::Type],
-    model_list_relations: &[syn::Ident],
+    fields: &[ModelField],
 ) -> (syn::Ident, proc_macro2::TokenStream) {
     let state_mut_struct_name = format_ident!("{}Mut", state_struct_name);
-    let set_fn_names = value_idents.iter().map(|i| format_ident!("set_{}", i));
-    let set_list_fn_names = value_list_idents.iter().map(|i| format_ident!("set_{}", i));
+    let mut fns = vec![];
 
-    let types = value_types
-        .iter()
-        .chain(model_types)
-        .chain(value_list_types)
-        .chain(model_list_types);
-    let idents = value_idents
-        .iter()
-        .chain(model_idents)
-        .chain(value_list_idents)
-        .chain(model_list_idents);
-    let relations = value_relations
-        .iter()
-        .chain(model_relations)
-        .chain(value_list_relations)
-        .chain(model_list_relations);
+    for field in fields {
+        let ident = &field.ident;
+        let ty = field.ty;
+        let setter = format_ident!("set_{}", ident);
+        let relation = relation_name(struct_name, ident);
+
+        match &field.kind {
+            FieldKind::Single => {
+                if let Some(bound) = field.setter_bound(ty) {
+                    fns.push(quote!(
+                        #visibility fn #setter<S: ::std::convert::Into<::std::rc::Rc<::std::primitive::str>>>(self, value: S) #bound {
+                            yfc::form_state::StateMut::map_set(self, &#relation, value);
+                        }
+                    ));
+                }
+            }
+            FieldKind::List(inner_ty) => {
+                let elem_relation = elem_relation_name(struct_name, ident);
+
+                if let Some(bound) = field.setter_bound(inner_ty) {
+                    fns.push(quote!(
+                        #visibility fn #setter<S: ::std::convert::Into<::std::rc::Rc<::std::primitive::str>>>(self, index: usize, value: S) #bound {
+                            yfc::form_state::StateMut::map_set(self, &#elem_relation(index), value);
+                        }
+                    ));
+                }
+            }
+            FieldKind::Tuple(elem_types) => {
+                for (index, elem_ty) in elem_types.iter().enumerate() {
+                    let elem_ident = format_ident!("{}_{}", ident, index);
+                    let elem_setter = format_ident!("set_{}", elem_ident);
+                    let elem_relation = tuple_relation_name(struct_name, ident, index);
+
+                    if let Some(bound) = field.setter_bound(elem_ty) {
+                        fns.push(quote!(
+                            #visibility fn #elem_setter<S: ::std::convert::Into<::std::rc::Rc<::std::primitive::str>>>(self, value: S) #bound {
+                                yfc::form_state::StateMut::map_set(self, &#elem_relation, value);
+                            }
+                        ));
+                    }
+
+                    fns.push(quote!(
+                        #visibility fn #elem_ident(self) -> <#elem_ty as yfc::form_state::StateProvider>::StateMut<'a> {
+                            yfc::form_state::StateMut::map(self, &#elem_relation)
+                        }
+                    ));
+                }
+            }
+        }
+
+        fns.push(quote!(
+            #visibility fn #ident(self) -> <#ty as yfc::form_state::StateProvider>::StateMut<'a> {
+                yfc::form_state::StateMut::map(self, &#relation)
+            }
+        ));
+    }
 
     let state_mut_struct_impl = quote!(
         #visibility struct #state_mut_struct_name<'a> {
@@ -472,24 +992,7 @@ fn expand_state_mut_struct(
         }
 
         impl<'a> #state_mut_struct_name<'a> {
-
-            #(
-                #visibility fn #set_fn_names<S: ::std::convert::Into<::std::rc::Rc<::std::primitive::str>>>(self, value: S) {
-                    let mut state = yfc::form_state::StateMut::map(self, &#value_relations);
-                    yfc::form_state::ValueStateMut::set(&mut state, value);
-                }
-            )*
-            #(
-                #visibility fn #set_list_fn_names<S: ::std::convert::Into<::std::rc::Rc<::std::primitive::str>>>(self, index: usize, value: S) {
-                    let mut state = yfc::form_state::StateMut::map(self, &#value_list_elem_relations(index));
-                    yfc::form_state::ValueStateMut::set(&mut state, value);
-                }
-            )*
-            #(
-                #visibility fn #idents(self) -> <#types as yfc::form_state::StateProvider>::StateMut<'a> {
-                    yfc::form_state::StateMut::map(self, &#relations)
-                }
-            )*
+            #(#fns)*
         }
 
         impl<'a> yfc::form_state::StateMut<'a, #struct_name> for #state_mut_struct_name<'a> {
@@ -510,55 +1013,113 @@ fn expand_state_mut_struct(
     (state_mut_struct_name, state_mut_struct_impl)
 }
 
+fn relation_name(struct_name: &syn::Ident, ident: &syn::Ident) -> syn::Ident {
+    format_ident!(
+        "{}{}Relation",
+        struct_name,
+        ident.to_string().to_pascal_case()
+    )
+}
+
+fn elem_relation_name(struct_name: &syn::Ident, ident: &syn::Ident) -> syn::Ident {
+    format_ident!(
+        "{}{}ElemRelation",
+        struct_name,
+        ident.to_string().to_pascal_case()
+    )
+}
+
+fn tuple_relation_name(struct_name: &syn::Ident, ident: &syn::Ident, index: usize) -> syn::Ident {
+    format_ident!(
+        "{}{}{}Relation",
+        struct_name,
+        ident.to_string().to_pascal_case(),
+        index
+    )
+}
+
 fn expand_relations(
+    visibility: &syn::Visibility,
     struct_name: &syn::Ident,
     state_struct_name: &syn::Ident,
-    types: &[&syn::Type],
-    idents: &[syn::Ident],
-    list: bool,
-) -> (Vec<syn::Ident>, proc_macro2::TokenStream) {
-    let relations = idents
-        .iter()
-        .map(|i| match list {
-            true => format_ident!(
-                "{}{}ElemRelation",
-                struct_name,
-                i.to_string().to_pascal_case()
-            ),
-            false => format_ident!("{}{}Relation", struct_name, i.to_string().to_pascal_case()),
-        })
-        .collect::<Vec<_>>();
+    fields: &[ModelField],
+) -> proc_macro2::TokenStream {
+    let mut relations = vec![];
+    let mut relation_structs = vec![];
+    let mut types = vec![];
+    let mut accessors = vec![];
+    let mut metas = vec![];
+    let mut transforms = vec![];
 
-    let idents = idents.iter().map(|i| match list {
-        true => quote!(#i[self.0]),
-        false => quote!(#i),
-    });
+    for field in fields {
+        let ident = &field.ident;
+        let name = &field.name;
+        let relation = relation_name(struct_name, ident);
 
-    let relation_structs = relations.iter().map(|r| match list {
-        true => quote!(#r(usize)),
-        false => quote!(#r),
-    });
+        relation_structs.push(quote!(#relation));
+        relations.push(relation);
+        types.push(field.ty);
+        accessors.push(quote!(#ident));
+        metas.push(field.meta(quote!(#name)));
+        transforms.push(field.input_transform());
+
+        match &field.kind {
+            FieldKind::Single => {}
+            FieldKind::List(inner_ty) => {
+                let relation = elem_relation_name(struct_name, ident);
 
-    let relation_structs = quote!(
+                relation_structs.push(quote!(#relation(#visibility usize)));
+                relations.push(relation);
+                types.push(inner_ty);
+                accessors.push(quote!(#ident[self.0]));
+                metas.push(field.meta(quote!(format!("{}[{}]", #name, self.0))));
+                transforms.push(field.input_transform());
+            }
+            FieldKind::Tuple(elem_types) => {
+                for (index, elem_ty) in elem_types.iter().enumerate() {
+                    let relation = tuple_relation_name(struct_name, ident, index);
+                    let elem_name = format!("{}.{}", name, index);
+                    let index = syn::Index::from(index);
+
+                    relation_structs.push(quote!(#relation));
+                    relations.push(relation);
+                    types.push(elem_ty);
+                    accessors.push(quote!(#ident.#index));
+                    metas.push(field.meta(quote!(#elem_name)));
+                    transforms.push(field.input_transform());
+                }
+            }
+        }
+    }
+
+    quote!(
         #(
-            struct #relation_structs;
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #visibility struct #relation_structs;
+
+            impl yfc::model::TypedRelation for #relations {
+                type Parent = #struct_name;
+                type Child = #types;
+            }
 
             impl yfc::model::ModelRelation<#struct_name, #types> for #relations {
                 fn relation_model<'a>(&self, parent: &'a #struct_name) -> &'a #types {
-                    &parent.#idents
+                    &parent.#accessors
                 }
                 fn relation_model_mut<'a>(&self, parent: &'a mut #struct_name) -> &'a mut #types {
-                    &mut parent.#idents
+                    &mut parent.#accessors
                 }
                 fn relation_state<'a>(&self, parent: &'a #state_struct_name) -> &'a <#types as yfc::form_state::StateProvider>::State {
-                    &parent.#idents
+                    &parent.#accessors
                 }
                 fn relation_state_mut<'a>(&self, parent: &'a mut #state_struct_name) -> &'a mut <#types as yfc::form_state::StateProvider>::State {
-                    &mut parent.#idents
+                    &mut parent.#accessors
+                }
+                fn meta(&self) -> yfc::meta::FieldMeta {
+                    #metas
                 }
+                #transforms
             }
         )*
-    );
-
-    (relations, relation_structs)
+    )
 }